@@ -0,0 +1,35 @@
+//! Compare two dataset snapshots and print the changes as JSON.
+//!
+//! ```text
+//! cargo run --example dataset_diff --features std -- export > celes-2.8.json
+//! cargo run --example dataset_diff --features std -- celes-2.8.json celes-2.9.json
+//! ```
+use celes::diff::{Snapshot, diff};
+use std::{env, fs, process::ExitCode};
+
+fn load(path: &str) -> Result<Snapshot, String> {
+    let json = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    Snapshot::from_json(&json).map_err(|e| format!("{path}: {e}"))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.as_slice() {
+        [cmd] if cmd == "export" => Ok(Snapshot::current().to_json()),
+        [old, new] => load(old).and_then(|old| {
+            let new = load(new)?;
+            serde_json::to_string_pretty(&diff(&old, &new)).map_err(|e| e.to_string())
+        }),
+        _ => Err("usage: dataset_diff export | dataset_diff <old.json> <new.json>".to_string()),
+    };
+    match result {
+        Ok(json) => {
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,167 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Country literals validated at compile time
+//!
+//! `country!` accepts anything `Country::from_str` accepts and expands to a
+//! constant `celes::Country`. Unknown inputs fail the build with suggestions.
+//!
+//! ```
+//! use celes::Country;
+//! use celes_macros::{countries, country};
+//!
+//! const DE: Country = country!("DE");
+//! assert_eq!(Country::germany(), DE);
+//! assert_eq!(Country::germany(), country!("276"));
+//! assert_eq!(Country::germany(), country!("Germany"));
+//!
+//! const NAFTA: [Country; 3] = countries!["US", "CA", "MX"];
+//! assert_eq!(Country::mexico(), NAFTA[2]);
+//! ```
+//!
+//! ```compile_fail
+//! use celes_macros::country;
+//!
+//! // error: unknown country `Germny`, did you mean `Germany`?
+//! let de = country!("Germny");
+//! ```
+//!
+//! ```compile_fail
+//! use celes_macros::countries;
+//!
+//! // error: `USA` is a duplicate of `US`
+//! let na = countries!["US", "CA", "USA"];
+//! ```
+
+use celes::Country;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{LitStr, Token, parse_macro_input, punctuated::Punctuated};
+
+/// The most suggestions listed for an unknown input
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Expand a country identifier to the matching `celes::Country` at compile time.
+///
+/// The input is any string accepted by `Country::from_str` such as `"DE"`,
+/// `"DEU"`, `"276"` or `"Germany"`.
+#[proc_macro]
+pub fn country(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    resolve(&lit)
+        .map_or_else(syn::Error::into_compile_error, expand)
+        .into()
+}
+
+/// Expand a list of country identifiers to an array of `celes::Country` at
+/// compile time. Each country may appear only once.
+#[proc_macro]
+pub fn countries(input: TokenStream) -> TokenStream {
+    let lits = parse_macro_input!(input with Punctuated::<LitStr, Token![,]>::parse_terminated);
+    let mut seen: Vec<(Country, String)> = Vec::with_capacity(lits.len());
+    let mut errors: Option<syn::Error> = None;
+    let mut add_error = |e: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(e),
+        None => errors = Some(e),
+    };
+    for lit in &lits {
+        match resolve(lit) {
+            Ok(country) => {
+                if let Some((_, first)) = seen.iter().find(|(c, _)| *c == country) {
+                    add_error(syn::Error::new(
+                        lit.span(),
+                        format!("`{}` is a duplicate of `{first}`", lit.value()),
+                    ));
+                } else {
+                    seen.push((country, lit.value()));
+                }
+            }
+            Err(e) => add_error(e),
+        }
+    }
+    if let Some(errors) = errors {
+        // Wrapped in a block so several errors are valid in expression position
+        let errors = errors.into_compile_error();
+        return quote!({ #errors }).into();
+    }
+    let countries = seen.into_iter().map(|(c, _)| expand(c));
+    quote!([#(#countries),*]).into()
+}
+
+fn resolve(lit: &LitStr) -> Result<Country, syn::Error> {
+    let value = lit.value();
+    value.parse::<Country>().map_err(|_| {
+        let suggestions = suggest(&value);
+        let message = if suggestions.is_empty() {
+            format!("unknown country `{value}`")
+        } else {
+            format!(
+                "unknown country `{value}`, did you mean {}?",
+                suggestions
+                    .iter()
+                    .map(|s| format!("`{s}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        syn::Error::new(lit.span(), message)
+    })
+}
+
+fn expand(country: Country) -> TokenStream2 {
+    let value = country.value();
+    let alpha2 = country.alpha2();
+    quote! {
+        const {
+            match ::celes::Country::from_value_const(#value) {
+                ::core::option::Option::Some(country) => country,
+                ::core::option::Option::None => panic!(concat!("unknown country ", #alpha2)),
+            }
+        }
+    }
+}
+
+/// The identifiers closest to `input` by edit distance ignoring case
+fn suggest(input: &str) -> Vec<&'static str> {
+    let input = input.to_lowercase();
+    let threshold = (input.chars().count() / 3).max(1);
+    let mut candidates: Vec<(usize, &'static str)> = Country::all()
+        .iter()
+        .flat_map(|c| {
+            [c.alpha2(), c.alpha3(), c.long_name()]
+                .into_iter()
+                .chain(c.aliases().as_slice().iter().copied())
+        })
+        .map(|id| (distance(&input, &id.to_lowercase()), id))
+        .filter(|(d, _)| *d <= threshold)
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup_by_key(|(_, id)| *id);
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, id)| id)
+        .collect()
+}
+
+/// Levenshtein distance between `a` and `b`
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,35 @@
+//! Country literal macro tests
+use celes::Country;
+use celes_macros::{countries, country};
+
+const GB: Country = country!("gb");
+
+#[test]
+fn every_identifier_kind() {
+    let gb = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
+    assert_eq!(gb, GB);
+    assert_eq!(gb, country!("GBR"));
+    assert_eq!(gb, country!("826"));
+    assert_eq!(gb, country!("UnitedKingdom"));
+    assert_eq!(
+        gb,
+        country!("the_united_kingdom_of_great_britain_and_northern_ireland")
+    );
+    assert_eq!(Country::the_netherlands(), country!("Holland"));
+}
+
+#[test]
+fn country_lists() {
+    static NORTH_AMERICA: [Country; 3] = countries!["US", "CA", "MX"];
+    assert_eq!(
+        [
+            Country::the_united_states_of_america(),
+            Country::canada(),
+            Country::mexico()
+        ],
+        NORTH_AMERICA
+    );
+    let empty: [Country; 0] = countries![];
+    assert!(empty.is_empty());
+    assert_eq!([Country::japan()], countries!["JPN",]);
+}
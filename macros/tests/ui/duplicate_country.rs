@@ -0,0 +1,5 @@
+use celes_macros::countries;
+
+fn main() {
+    let _ = countries!["US", "CA", "USA"];
+}
@@ -0,0 +1,5 @@
+use celes_macros::country;
+
+fn main() {
+    let _ = country!("Germny");
+}
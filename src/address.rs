@@ -0,0 +1,698 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Postal address formats for each country based on the data
+//! published by Google's libaddressinput and the Universal Postal Union
+//!
+//! Templates use the libaddressinput notation where each field is written as
+//! `%` followed by the field code and `%n` is a line break:
+//!
+//! - `N` - name
+//! - `O` - organization
+//! - `A` - street address
+//! - `D` - dependent locality such as a suburb or neighborhood
+//! - `C` - locality such as a city or town
+//! - `S` - administrative area such as a state, province or prefecture
+//! - `Z` - postal code
+//! - `X` - sorting code
+//!
+//! Any other text in a template is printed as is.
+
+use crate::Country;
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+use phf::{Map, phf_map};
+
+/// A field in a postal address
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AddressField {
+    /// The name of the recipient
+    Name,
+    /// The organization of the recipient
+    Organization,
+    /// The street address, which can span multiple lines
+    StreetAddress,
+    /// A dependent locality such as a suburb or neighborhood
+    DependentLocality,
+    /// The locality such as a city or town
+    Locality,
+    /// The top level administrative area such as a state, province or prefecture
+    AdministrativeArea,
+    /// The postal code
+    PostalCode,
+    /// The sorting code such as the French CEDEX
+    SortingCode,
+}
+
+impl AddressField {
+    /// The code used for this field in templates
+    #[must_use]
+    pub const fn code(self) -> char {
+        match self {
+            Self::Name => 'N',
+            Self::Organization => 'O',
+            Self::StreetAddress => 'A',
+            Self::DependentLocality => 'D',
+            Self::Locality => 'C',
+            Self::AdministrativeArea => 'S',
+            Self::PostalCode => 'Z',
+            Self::SortingCode => 'X',
+        }
+    }
+
+    /// The field for a template code or `None` if the code is unknown
+    #[must_use]
+    pub const fn from_code(code: char) -> Option<Self> {
+        match code {
+            'N' => Some(Self::Name),
+            'O' => Some(Self::Organization),
+            'A' => Some(Self::StreetAddress),
+            'D' => Some(Self::DependentLocality),
+            'C' => Some(Self::Locality),
+            'S' => Some(Self::AdministrativeArea),
+            'Z' => Some(Self::PostalCode),
+            'X' => Some(Self::SortingCode),
+            _ => None,
+        }
+    }
+}
+
+/// What the administrative area is called in a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubdivisionName {
+    /// Area
+    Area,
+    /// County
+    County,
+    /// Department
+    Department,
+    /// District
+    District,
+    /// Do/Si
+    DoSi,
+    /// Emirate
+    Emirate,
+    /// Island
+    Island,
+    /// Oblast
+    Oblast,
+    /// Parish
+    Parish,
+    /// Prefecture
+    Prefecture,
+    /// Province
+    Province,
+    /// State
+    State,
+}
+
+impl Display for SubdivisionName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Self::Area => "area",
+            Self::County => "county",
+            Self::Department => "department",
+            Self::District => "district",
+            Self::DoSi => "do/si",
+            Self::Emirate => "emirate",
+            Self::Island => "island",
+            Self::Oblast => "oblast",
+            Self::Parish => "parish",
+            Self::Prefecture => "prefecture",
+            Self::Province => "province",
+            Self::State => "state",
+        })
+    }
+}
+
+/// What the locality is called in a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LocalityName {
+    /// City
+    City,
+    /// District
+    District,
+    /// Post town
+    PostTown,
+    /// Suburb
+    Suburb,
+}
+
+impl Display for LocalityName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Self::City => "city",
+            Self::District => "district",
+            Self::PostTown => "post town",
+            Self::Suburb => "suburb",
+        })
+    }
+}
+
+/// What the postal code is called in a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PostalCodeName {
+    /// Eircode
+    Eircode,
+    /// PIN code
+    Pin,
+    /// Postal code
+    Postal,
+    /// ZIP code
+    Zip,
+}
+
+impl Display for PostalCodeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Self::Eircode => "eircode",
+            Self::Pin => "pin code",
+            Self::Postal => "postal code",
+            Self::Zip => "zip code",
+        })
+    }
+}
+
+/// A structured postal address. Empty fields are left out when formatting.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Address<'a> {
+    /// The name of the recipient
+    pub name: &'a str,
+    /// The organization of the recipient
+    pub organization: &'a str,
+    /// The street address. Use `\n` to separate multiple lines.
+    pub street_address: &'a str,
+    /// A dependent locality such as a suburb or neighborhood
+    pub dependent_locality: &'a str,
+    /// The locality such as a city or town
+    pub locality: &'a str,
+    /// The top level administrative area such as a state, province or prefecture
+    pub administrative_area: &'a str,
+    /// The postal code
+    pub postal_code: &'a str,
+    /// The sorting code such as the French CEDEX
+    pub sorting_code: &'a str,
+}
+
+impl<'a> Address<'a> {
+    /// The trimmed value of `field`
+    #[must_use]
+    pub fn get(&self, field: AddressField) -> &'a str {
+        match field {
+            AddressField::Name => self.name,
+            AddressField::Organization => self.organization,
+            AddressField::StreetAddress => self.street_address,
+            AddressField::DependentLocality => self.dependent_locality,
+            AddressField::Locality => self.locality,
+            AddressField::AdministrativeArea => self.administrative_area,
+            AddressField::PostalCode => self.postal_code,
+            AddressField::SortingCode => self.sorting_code,
+        }
+        .trim()
+    }
+}
+
+/// How postal addresses are written in a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AddressFormat {
+    /// The template for the address lines
+    pub template: &'static str,
+    /// The codes of the fields that are required
+    pub required: &'static str,
+    /// The codes of the fields that are written in upper case
+    pub uppercase: &'static str,
+    /// What the administrative area is called
+    pub subdivision_name: SubdivisionName,
+    /// What the locality is called
+    pub locality_name: LocalityName,
+    /// What the postal code is called
+    pub postal_code_name: PostalCodeName,
+}
+
+impl Default for AddressFormat {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl AddressFormat {
+    /// The format used by countries without a specific convention
+    pub const DEFAULT: Self = Self::new("%N%n%O%n%A%n%C", "AC", "C");
+
+    const fn new(template: &'static str, required: &'static str, uppercase: &'static str) -> Self {
+        Self {
+            template,
+            required,
+            uppercase,
+            subdivision_name: SubdivisionName::Province,
+            locality_name: LocalityName::City,
+            postal_code_name: PostalCodeName::Postal,
+        }
+    }
+
+    const fn subdivision(mut self, name: SubdivisionName) -> Self {
+        self.subdivision_name = name;
+        self
+    }
+
+    const fn locality(mut self, name: LocalityName) -> Self {
+        self.locality_name = name;
+        self
+    }
+
+    const fn postal_code(mut self, name: PostalCodeName) -> Self {
+        self.postal_code_name = name;
+        self
+    }
+
+    /// The fields in the order they appear in the template
+    ///
+    /// ```
+    /// use celes::{AddressField, Country};
+    ///
+    /// let fields = Country::japan().address_format().fields().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     fields,
+    ///     [
+    ///         AddressField::Name,
+    ///         AddressField::Organization,
+    ///         AddressField::StreetAddress,
+    ///         AddressField::AdministrativeArea,
+    ///         AddressField::PostalCode,
+    ///     ]
+    /// );
+    /// ```
+    pub fn fields(&self) -> impl Iterator<Item = AddressField> + use<> {
+        self.template
+            .split('%')
+            .skip(1)
+            .filter_map(|t| t.chars().next().and_then(AddressField::from_code))
+    }
+
+    /// True if `field` must be filled in
+    #[must_use]
+    pub fn is_required(&self, field: AddressField) -> bool {
+        self.required.contains(field.code())
+    }
+
+    /// True if `field` is written in upper case
+    #[must_use]
+    pub fn is_uppercase(&self, field: AddressField) -> bool {
+        self.uppercase.contains(field.code())
+    }
+
+    /// The first required field that is empty in `address` or `None` if all are present
+    ///
+    /// ```
+    /// use celes::{Address, AddressField, Country};
+    ///
+    /// let address = Address {
+    ///     street_address: "1600 Amphitheatre Parkway",
+    ///     locality: "Mountain View",
+    ///     postal_code: "94043",
+    ///     ..Address::default()
+    /// };
+    /// let format = Country::the_united_states_of_america().address_format();
+    /// assert_eq!(Some(AddressField::AdministrativeArea), format.missing_field(&address));
+    /// ```
+    #[must_use]
+    pub fn missing_field(&self, address: &Address<'_>) -> Option<AddressField> {
+        self.fields()
+            .find(|f| self.is_required(*f) && address.get(*f).is_empty())
+    }
+
+    /// Render `address` according to this format
+    #[must_use]
+    pub const fn format<'a>(&self, address: &'a Address<'a>) -> FormattedAddress<'a> {
+        FormattedAddress {
+            format: *self,
+            address,
+        }
+    }
+}
+
+/// An address rendered with the convention of a country
+///
+/// Literal text next to an empty field is dropped, as are lines without any content.
+#[derive(Copy, Clone, Debug)]
+pub struct FormattedAddress<'a> {
+    format: AddressFormat,
+    address: &'a Address<'a>,
+}
+
+/// A piece of a template line
+#[derive(Copy, Clone)]
+enum Token {
+    Literal(&'static str),
+    Field(AddressField),
+}
+
+fn tokens(line: &'static str) -> impl Iterator<Item = Token> {
+    let mut pieces = line.split('%');
+    let first = pieces.next().filter(|s| !s.is_empty()).map(Token::Literal);
+    first.into_iter().chain(pieces.flat_map(|piece| {
+        let mut chars = piece.chars();
+        let field = chars
+            .next()
+            .and_then(AddressField::from_code)
+            .map(Token::Field);
+        let rest = chars.as_str();
+        field
+            .into_iter()
+            .chain((!rest.is_empty()).then_some(Token::Literal(rest)))
+    }))
+}
+
+impl FormattedAddress<'_> {
+    fn value(&self, field: AddressField) -> &str {
+        self.address.get(field)
+    }
+
+    fn write_value(&self, f: &mut Formatter<'_>, field: AddressField) -> FmtResult {
+        let value = self.value(field);
+        if self.format.is_uppercase(field) {
+            for c in value.chars().flat_map(char::to_uppercase) {
+                f.write_char(c)?;
+            }
+            Ok(())
+        } else {
+            f.write_str(value)
+        }
+    }
+
+    fn has_content(&self, line: &'static str) -> bool {
+        let mut has_fields = false;
+        for token in tokens(line) {
+            if let Token::Field(field) = token {
+                if !self.value(field).is_empty() {
+                    return true;
+                }
+                has_fields = true;
+            }
+        }
+        !has_fields && !line.trim().is_empty()
+    }
+
+    fn write_line(&self, f: &mut Formatter<'_>, line: &'static str) -> FmtResult {
+        let mut started = false;
+        let mut keep_leading = true;
+        let mut pending = "";
+        let mut has_fields = false;
+        for token in tokens(line) {
+            match token {
+                Token::Literal(s) => pending = s,
+                Token::Field(field) => {
+                    has_fields = true;
+                    if self.value(field).is_empty() {
+                        keep_leading &= started;
+                    } else {
+                        if started || keep_leading {
+                            f.write_str(pending)?;
+                        }
+                        self.write_value(f, field)?;
+                        started = true;
+                    }
+                    pending = "";
+                }
+            }
+        }
+        if has_fields {
+            Ok(())
+        } else {
+            f.write_str(pending)
+        }
+    }
+}
+
+impl Display for FormattedAddress<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut first = true;
+        for line in self.format.template.split("%n") {
+            if !self.has_content(line) {
+                continue;
+            }
+            if !first {
+                f.write_char('\n')?;
+            }
+            self.write_line(f, line)?;
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+/// Address formats keyed by alpha2. Countries without an entry use [`AddressFormat::DEFAULT`].
+static ADDRESS_FORMATS: Map<&'static str, AddressFormat> = phf_map! {
+    "AD" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "A", ""),
+    "AE" => AddressFormat::new("%N%n%O%n%A%n%S", "AS", "").subdivision(SubdivisionName::Emirate),
+    "AF" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "C"),
+    "AI" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "C"),
+    "AL" => AddressFormat::new("%N%n%O%n%A%n%Z%n%C", "AC", "C"),
+    "AM" => AddressFormat::new("%N%n%O%n%A%n%Z%n%C%n%S", "AC", "C"),
+    "AR" => AddressFormat::new("%N%n%O%n%A%n%Z %C%n%S", "AC", "ACZ"),
+    "AS" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "AT" => AddressFormat::new("%O%n%N%n%A%n%Z %C", "ACZ", ""),
+    "AU" => AddressFormat::new("%O%n%N%n%A%n%C %S %Z", "ACSZ", "CS").subdivision(SubdivisionName::State).locality(LocalityName::Suburb),
+    "AX" => AddressFormat::new("%O%n%N%n%A%nAX-%Z %C%nÅLAND", "ACZ", "C"),
+    "AZ" => AddressFormat::new("%N%n%O%n%A%nAZ %Z %C", "AC", "C"),
+    "BA" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "BB" => AddressFormat::new("%N%n%O%n%A%n%C, %S %Z", "AC", "C").subdivision(SubdivisionName::Parish),
+    "BD" => AddressFormat::new("%N%n%O%n%A%n%C - %Z", "AC", "C"),
+    "BE" => AddressFormat::new("%O%n%N%n%A%n%Z %C", "ACZ", ""),
+    "BG" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "BH" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "BL" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "BM" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "BN" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "BR" => AddressFormat::new("%O%n%N%n%A%n%D%n%C-%S%n%Z", "ACSZ", "CS").subdivision(SubdivisionName::State),
+    "BS" => AddressFormat::new("%N%n%O%n%A%n%C, %S", "AC", "C").subdivision(SubdivisionName::Island),
+    "BT" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "BY" => AddressFormat::new("%O%n%N%n%A%n%Z, %C%n%S", "ACSZ", "").subdivision(SubdivisionName::Oblast),
+    "CA" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOSZ"),
+    "CC" => AddressFormat::new("%O%n%N%n%A%n%C %S %Z", "AC", "CS"),
+    "CH" => AddressFormat::new("%O%n%N%n%A%nCH-%Z %C", "ACZ", ""),
+    "CI" => AddressFormat::new("%N%n%O%n%X %A %C %X", "AC", "C"),
+    "CL" => AddressFormat::new("%N%n%O%n%A%n%Z %C%n%S", "AC", "C"),
+    "CN" => AddressFormat::new("%N%n%O%n%A%n%D%n%C%n%S, %Z", "ACSZ", "S"),
+    "CO" => AddressFormat::new("%N%n%O%n%A%n%D%n%C, %S, %Z", "AS", "C").subdivision(SubdivisionName::Department),
+    "CR" => AddressFormat::new("%N%n%O%n%A%n%S, %C%n%Z", "ACS", "C"),
+    "CU" => AddressFormat::new("%N%n%O%n%A%n%C %S%n%Z", "AC", "C"),
+    "CV" => AddressFormat::new("%N%n%O%n%A%n%Z %C%n%S", "AC", "C").subdivision(SubdivisionName::Island),
+    "CX" => AddressFormat::new("%O%n%N%n%A%n%C %S %Z", "AC", "CS"),
+    "CY" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "CZ" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "DE" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "DK" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "DO" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "DZ" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "EC" => AddressFormat::new("%N%n%O%n%A%n%Z%n%C", "AC", "CZ"),
+    "EE" => AddressFormat::new("%N%n%O%n%A%n%Z %C %S", "ACZ", "").subdivision(SubdivisionName::County),
+    "EG" => AddressFormat::new("%N%n%O%n%A%n%C%n%S%n%Z", "AC", "C"),
+    "EH" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "ES" => AddressFormat::new("%N%n%O%n%A%n%Z %C %S", "ACSZ", "CS"),
+    "ET" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "FI" => AddressFormat::new("%O%n%N%n%A%nFI-%Z %C", "ACZ", ""),
+    "FK" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "FM" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "FO" => AddressFormat::new("%N%n%O%n%A%nFO%Z %C", "AC", "C"),
+    "FR" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "CX"),
+    "GB" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ").subdivision(SubdivisionName::County).locality(LocalityName::PostTown),
+    "GE" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "GF" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "GG" => AddressFormat::new("%N%n%O%n%A%n%C%nGUERNSEY%n%Z", "ACZ", "CZ"),
+    "GI" => AddressFormat::new("%N%n%O%n%A%nGIBRALTAR%n%Z", "A", ""),
+    "GL" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "GN" => AddressFormat::new("%N%n%O%n%Z %A %C", "AC", "C"),
+    "GP" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "GR" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "GS" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "GT" => AddressFormat::new("%N%n%O%n%A%n%Z- %C", "AC", "C"),
+    "GU" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "ACZ", "ACNO").postal_code(PostalCodeName::Zip),
+    "GW" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "HK" => AddressFormat::new("%N%n%O%n%A%n%C%n%S", "AS", "S").subdivision(SubdivisionName::Area).locality(LocalityName::District),
+    "HM" => AddressFormat::new("%O%n%N%n%A%n%C %S %Z", "AC", "CS"),
+    "HN" => AddressFormat::new("%N%n%O%n%A%n%C, %S%n%Z", "ACS", "CS").subdivision(SubdivisionName::Department),
+    "HR" => AddressFormat::new("%N%n%O%n%A%nHR-%Z %C", "AC", "C"),
+    "HT" => AddressFormat::new("%N%n%O%n%A%nHT%Z %C", "AC", "C"),
+    "HU" => AddressFormat::new("%N%n%O%n%C%n%A%n%Z", "ACZ", "ACNO").subdivision(SubdivisionName::County),
+    "ID" => AddressFormat::new("%N%n%O%n%A%n%C%n%S %Z", "AS", "C"),
+    "IE" => AddressFormat::new("%N%n%O%n%A%n%D%n%C%n%S%n%Z", "AC", "").subdivision(SubdivisionName::County).postal_code(PostalCodeName::Eircode),
+    "IL" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "IM" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "IN" => AddressFormat::new("%N%n%O%n%A%n%C %Z%n%S", "ACSZ", "").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Pin),
+    "IO" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "IQ" => AddressFormat::new("%O%n%N%n%A%n%C, %S%n%Z", "ACS", "CS"),
+    "IR" => AddressFormat::new("%O%n%N%n%S%n%C, %D%n%A%n%Z", "AC", "C"),
+    "IS" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "IT" => AddressFormat::new("%N%n%O%n%A%n%Z %C %S", "ACSZ", "CS"),
+    "JE" => AddressFormat::new("%N%n%O%n%A%n%C%nJERSEY%n%Z", "ACZ", "CZ"),
+    "JM" => AddressFormat::new("%N%n%O%n%A%n%C%n%S %X", "ACS", "C").subdivision(SubdivisionName::Parish),
+    "JO" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "JP" => AddressFormat::new("%N%n%O%n%A, %S%n%Z", "ASZ", "S").subdivision(SubdivisionName::Prefecture),
+    "KE" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "C"),
+    "KG" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "KH" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "KI" => AddressFormat::new("%N%n%O%n%A%n%S%n%C", "AC", "ACNOS").subdivision(SubdivisionName::Island),
+    "KN" => AddressFormat::new("%N%n%O%n%A%n%C, %S", "ACS", "C").subdivision(SubdivisionName::Island),
+    "KP" => AddressFormat::new("%N%n%O%n%A%n%C%n%S, %Z", "AC", "C"),
+    "KR" => AddressFormat::new("%N%n%O%n%A%n%D%n%C%n%S%n%Z", "ACSZ", "Z").subdivision(SubdivisionName::DoSi),
+    "KW" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "KY" => AddressFormat::new("%N%n%O%n%A%n%S %Z", "AS", "C").subdivision(SubdivisionName::Island),
+    "KZ" => AddressFormat::new("%Z%n%S%n%C%n%A%n%O%n%N", "AC", "C"),
+    "LA" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "LB" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "LI" => AddressFormat::new("%O%n%N%n%A%nFL-%Z %C", "ACZ", ""),
+    "LK" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "C"),
+    "LR" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "LS" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "LT" => AddressFormat::new("%O%n%N%n%A%nLT-%Z %C %S", "ACZ", "").subdivision(SubdivisionName::County),
+    "LU" => AddressFormat::new("%O%n%N%n%A%nL-%Z %C", "ACZ", ""),
+    "LV" => AddressFormat::new("%N%n%O%n%A%n%S%n%C, %Z", "ACZ", ""),
+    "MA" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "MC" => AddressFormat::new("%N%n%O%n%A%nMC-%Z %C %X", "AC", "C"),
+    "MD" => AddressFormat::new("%N%n%O%n%A%nMD-%Z %C", "AC", "C"),
+    "ME" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "MF" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "MG" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "MH" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "MK" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "MM" => AddressFormat::new("%N%n%O%n%A%n%C, %Z", "AC", "C"),
+    "MN" => AddressFormat::new("%N%n%O%n%A%n%C%n%S %Z", "AC", "C"),
+    "MO" => AddressFormat::new("%N%n%O%n%A", "A", ""),
+    "MP" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "MQ" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "MT" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "CZ"),
+    "MU" => AddressFormat::new("%N%n%O%n%A%n%Z%n%C", "AC", "CZ"),
+    "MV" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "MW" => AddressFormat::new("%N%n%O%n%A%n%C %X", "AC", "C"),
+    "MX" => AddressFormat::new("%N%n%O%n%A%n%D%n%Z %C, %S", "ACSZ", "CSZ").subdivision(SubdivisionName::State),
+    "MY" => AddressFormat::new("%N%n%O%n%A%n%D%n%Z %C%n%S", "ACZ", "CS").subdivision(SubdivisionName::State),
+    "MZ" => AddressFormat::new("%N%n%O%n%A%n%Z %C%S", "AC", "C"),
+    "NA" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "CZ"),
+    "NC" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "NE" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "NF" => AddressFormat::new("%O%n%N%n%A%n%C %S %Z", "AC", "CS"),
+    "NG" => AddressFormat::new("%N%n%O%n%A%n%D%n%C %Z%n%S", "AC", "CS").subdivision(SubdivisionName::State),
+    "NI" => AddressFormat::new("%N%n%O%n%A%n%Z%n%C, %S", "AC", "CS").subdivision(SubdivisionName::Department),
+    "NL" => AddressFormat::new("%O%n%N%n%A%n%Z %C", "ACZ", ""),
+    "NO" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", "").locality(LocalityName::PostTown),
+    "NP" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "NR" => AddressFormat::new("%N%n%O%n%A%n%S", "AS", "").subdivision(SubdivisionName::District),
+    "NZ" => AddressFormat::new("%N%n%O%n%A%n%D%n%C %Z", "ACZ", ""),
+    "OM" => AddressFormat::new("%N%n%O%n%A%n%Z%n%C", "AC", "C"),
+    "PA" => AddressFormat::new("%N%n%O%n%A%n%C%n%S", "AC", "CS"),
+    "PE" => AddressFormat::new("%N%n%O%n%A%n%C %Z%n%S", "AC", "C").subdivision(SubdivisionName::Department),
+    "PF" => AddressFormat::new("%N%n%O%n%A%n%Z %C %S", "ACSZ", "CS").subdivision(SubdivisionName::Island),
+    "PG" => AddressFormat::new("%N%n%O%n%A%n%C %Z %S", "ACS", "C"),
+    "PH" => AddressFormat::new("%N%n%O%n%A%n%D, %C%n%Z %S", "AC", "C"),
+    "PK" => AddressFormat::new("%N%n%O%n%A%n%D%n%C-%Z", "AC", "C"),
+    "PL" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "PM" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "PN" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "PR" => AddressFormat::new("%N%n%O%n%A%n%C PR %Z", "ACZ", "ACNO").postal_code(PostalCodeName::Zip),
+    "PT" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "PW" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "PY" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "RE" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "RO" => AddressFormat::new("%N%n%O%n%A%n%Z %S %C", "ACZ", "AC").subdivision(SubdivisionName::County),
+    "RS" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "RU" => AddressFormat::new("%N%n%O%n%A%n%C%n%S%n%Z", "ACSZ", "AC").subdivision(SubdivisionName::Oblast),
+    "SA" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "SC" => AddressFormat::new("%N%n%O%n%A%n%C%n%S", "AC", "S").subdivision(SubdivisionName::Island),
+    "SD" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "C").locality(LocalityName::District),
+    "SE" => AddressFormat::new("%O%n%N%n%A%nSE-%Z %C", "ACZ", "").locality(LocalityName::PostTown),
+    "SG" => AddressFormat::new("%N%n%O%n%A%nSINGAPORE %Z", "AZ", ""),
+    "SH" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "SI" => AddressFormat::new("%N%n%O%n%A%nSI-%Z %C", "AC", "C"),
+    "SJ" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", "").locality(LocalityName::PostTown),
+    "SK" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+    "SM" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AZ", ""),
+    "SN" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "SO" => AddressFormat::new("%N%n%O%n%A%n%C, %S %Z", "ACS", "ACS"),
+    "SR" => AddressFormat::new("%N%n%O%n%A%n%C%n%S", "AC", "AS"),
+    "SV" => AddressFormat::new("%N%n%O%n%A%n%Z-%C%n%S", "ACS", "CSZ").subdivision(SubdivisionName::Department),
+    "SZ" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "AC", "ACZ"),
+    "TC" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+    "TH" => AddressFormat::new("%N%n%O%n%A%n%D, %C%n%S %Z", "AC", "S"),
+    "TJ" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "TM" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "TN" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "TR" => AddressFormat::new("%N%n%O%n%A%n%Z %C/%S", "ACZ", "").locality(LocalityName::District),
+    "TV" => AddressFormat::new("%N%n%O%n%A%n%C%n%S", "AC", "ACS").subdivision(SubdivisionName::Island),
+    "TW" => AddressFormat::new("%N%n%O%n%A%n%C, %S %Z", "ACSZ", "").subdivision(SubdivisionName::County),
+    "TZ" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "UA" => AddressFormat::new("%N%n%O%n%A%n%C%n%S%n%Z", "ACZ", "").subdivision(SubdivisionName::Oblast),
+    "UM" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACS", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "US" => AddressFormat::new("%N%n%O%n%A%n%C, %S %Z", "ACSZ", "CS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "UY" => AddressFormat::new("%N%n%O%n%A%n%Z %C %S", "AC", "CS"),
+    "UZ" => AddressFormat::new("%N%n%O%n%A%n%Z %C%n%S", "AC", "CS"),
+    "VA" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "VC" => AddressFormat::new("%N%n%O%n%A%n%C %Z", "AC", "C"),
+    "VE" => AddressFormat::new("%N%n%O%n%A%n%C %Z, %S", "ACS", "CS").subdivision(SubdivisionName::State),
+    "VG" => AddressFormat::new("%N%n%O%n%A%n%C%n%Z", "A", ""),
+    "VI" => AddressFormat::new("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").subdivision(SubdivisionName::State).postal_code(PostalCodeName::Zip),
+    "VN" => AddressFormat::new("%N%n%O%n%A%n%C%n%S %Z", "AC", "C"),
+    "WF" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "XK" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+    "YT" => AddressFormat::new("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+    "ZA" => AddressFormat::new("%N%n%O%n%A%n%D%n%C%n%Z", "ACZ", "ACZ"),
+    "ZM" => AddressFormat::new("%N%n%O%n%A%n%Z %C", "AC", "C"),
+};
+
+impl Country {
+    /// The postal address format used by this country
+    ///
+    /// ```
+    /// use celes::{AddressField, Country, SubdivisionName};
+    ///
+    /// let format = Country::the_united_states_of_america().address_format();
+    /// assert_eq!(SubdivisionName::State, format.subdivision_name);
+    /// assert!(format.is_required(AddressField::PostalCode));
+    /// assert!(format.is_uppercase(AddressField::Locality));
+    ///
+    /// assert_eq!(SubdivisionName::Prefecture, Country::japan().address_format().subdivision_name);
+    /// ```
+    #[must_use]
+    pub fn address_format(&self) -> AddressFormat {
+        ADDRESS_FORMATS
+            .get(self.alpha2())
+            .copied()
+            .unwrap_or(AddressFormat::DEFAULT)
+    }
+
+    /// Render `address` according to this country's convention.
+    /// The country name is not included.
+    ///
+    /// ```
+    /// use celes::{Address, Country};
+    ///
+    /// let address = Address {
+    ///     name: "Jane Doe",
+    ///     street_address: "1600 Amphitheatre Parkway",
+    ///     locality: "Mountain View",
+    ///     administrative_area: "CA",
+    ///     postal_code: "94043",
+    ///     ..Address::default()
+    /// };
+    /// assert_eq!(
+    ///     "Jane Doe\n1600 Amphitheatre Parkway\nMOUNTAIN VIEW, CA 94043",
+    ///     Country::the_united_states_of_america().format_address(&address).to_string(),
+    /// );
+    ///
+    /// let address = Address {
+    ///     organization: "Example GmbH",
+    ///     street_address: "Bahnhofstrasse 1",
+    ///     locality: "Zürich",
+    ///     postal_code: "8001",
+    ///     ..Address::default()
+    /// };
+    /// assert_eq!(
+    ///     "Example GmbH\nBahnhofstrasse 1\nCH-8001 Zürich",
+    ///     Country::switzerland().format_address(&address).to_string(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn format_address<'a>(&self, address: &'a Address<'a>) -> FormattedAddress<'a> {
+        self.address_format().format(address)
+    }
+}
@@ -0,0 +1,79 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Lookups from raw bytes for decoders that never hold a `&str`
+
+use crate::Country;
+
+impl Country {
+    /// Given the alpha2 letters as bytes, return a country or an error if
+    /// they don't match any country. This is case-insensitive, never allocates
+    /// and rejects anything but two ASCII letters without a lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"invalid alpha2"` if the bytes do not match any known country.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Country::germany(), Country::from_alpha2_bytes(*b"DE").expect("alpha2"));
+    /// assert_eq!(Country::germany(), Country::from_alpha2_bytes(&b"de"[..]).expect("alpha2"));
+    /// assert!(Country::from_alpha2_bytes([0xC3, 0x9F]).is_err());
+    /// ```
+    pub fn from_alpha2_bytes<B: AsRef<[u8]>>(alpha2: B) -> Result<Self, &'static str> {
+        let bytes = alpha2.as_ref();
+        if bytes.len() != 2 || !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return Err("invalid alpha2");
+        }
+        // SAFETY: the bytes were checked to be ASCII letters
+        Self::from_alpha2(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Given the alpha3 letters as bytes, return a country or an error if
+    /// they don't match any country. This is case-insensitive, never allocates
+    /// and rejects anything but three ASCII letters without a lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"invalid alpha3"` if the bytes do not match any known country.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Country::germany(), Country::from_alpha3_bytes(*b"DEU").expect("alpha3"));
+    /// assert!(Country::from_alpha3_bytes(b"DE").is_err());
+    /// ```
+    pub fn from_alpha3_bytes<B: AsRef<[u8]>>(alpha3: B) -> Result<Self, &'static str> {
+        let bytes = alpha3.as_ref();
+        if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return Err("invalid alpha3");
+        }
+        // SAFETY: the bytes were checked to be ASCII letters
+        Self::from_alpha3(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Given the three digit numeric code as ASCII bytes, return a country or an
+    /// error if they don't match any country. This never allocates and rejects
+    /// anything but three ASCII digits without a lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"invalid code"` if the bytes do not match any known country.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Country::albania(), Country::from_numeric_bytes(*b"008").expect("numeric"));
+    /// assert!(Country::from_numeric_bytes(b"8").is_err());
+    /// ```
+    pub fn from_numeric_bytes<B: AsRef<[u8]>>(code: B) -> Result<Self, &'static str> {
+        let bytes = code.as_ref();
+        if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_digit) {
+            return Err("invalid code");
+        }
+        // SAFETY: the bytes were checked to be ASCII digits
+        Self::from_code(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+}
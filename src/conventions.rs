@@ -0,0 +1,424 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Locale conventions for each country such as the driving side, measurement system,
+//! paper size, calendar and number formats.
+//!
+//! The calendar and number formats follow the Unicode CLDR territory data
+//! for the most widely used language in each country.
+
+use crate::Country;
+use phf::{Map, phf_map};
+
+/// Creates a `Conventions` for the table below
+macro_rules! conventions {
+    ($side:ident, $measurement:ident, $paper:ident, $first:ident, $weekend_start:ident - $weekend_end:ident, $order:ident, $decimal:literal, $grouping:literal) => {
+        Conventions {
+            driving_side: DrivingSide::$side,
+            measurement_system: MeasurementSystem::$measurement,
+            paper_size: PaperSize::$paper,
+            first_day_of_week: Weekday::$first,
+            weekend_start: Weekday::$weekend_start,
+            weekend_end: Weekday::$weekend_end,
+            date_order: DateOrder::$order,
+            decimal_separator: $decimal,
+            grouping_separator: $grouping,
+        }
+    };
+}
+
+/// The side of the road traffic drives on
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DrivingSide {
+    /// Traffic keeps to the left
+    Left,
+    /// Traffic keeps to the right
+    Right,
+}
+
+/// The system of units used in everyday life
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MeasurementSystem {
+    /// The International System of Units
+    Metric,
+    /// The British imperial system
+    Imperial,
+    /// The United States customary system
+    UsCustomary,
+}
+
+/// The standard paper size
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PaperSize {
+    /// ISO 216 A4, 210 x 297 mm
+    A4,
+    /// US Letter, 8.5 x 11 in
+    Letter,
+}
+
+/// A day of the week
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Monday
+    Monday,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+    /// Sunday
+    Sunday,
+}
+
+/// The order of the day, month and year in numeric dates
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateOrder {
+    /// Day, month, year e.g. 31/12/2024
+    Dmy,
+    /// Month, day, year e.g. 12/31/2024
+    Mdy,
+    /// Year, month, day e.g. 2024-12-31
+    Ymd,
+}
+
+/// The everyday conventions used in a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Conventions {
+    /// The side of the road traffic drives on
+    pub driving_side: DrivingSide,
+    /// The system of units used in everyday life
+    pub measurement_system: MeasurementSystem,
+    /// The standard paper size
+    pub paper_size: PaperSize,
+    /// The first day of the week shown in calendars
+    pub first_day_of_week: Weekday,
+    /// The first day of the weekend
+    pub weekend_start: Weekday,
+    /// The last day of the weekend, which is the same as `weekend_start` for one day weekends
+    pub weekend_end: Weekday,
+    /// The order of the day, month and year in numeric dates
+    pub date_order: DateOrder,
+    /// The decimal separator for numbers
+    pub decimal_separator: char,
+    /// The thousands grouping separator for numbers
+    pub grouping_separator: char,
+}
+
+impl Conventions {
+    /// True if `day` falls on the weekend
+    ///
+    /// ```
+    /// use celes::{Country, Weekday};
+    ///
+    /// let us = Country::the_united_states_of_america().conventions();
+    /// assert!(us.is_weekend(Weekday::Sunday));
+    /// assert!(!us.is_weekend(Weekday::Friday));
+    ///
+    /// let ir = Country::islamic_republic_of_iran().conventions();
+    /// assert!(ir.is_weekend(Weekday::Friday));
+    /// assert!(!ir.is_weekend(Weekday::Sunday));
+    /// ```
+    #[must_use]
+    pub fn is_weekend(&self, day: Weekday) -> bool {
+        if self.weekend_start <= self.weekend_end {
+            self.weekend_start <= day && day <= self.weekend_end
+        } else {
+            day >= self.weekend_start || day <= self.weekend_end
+        }
+    }
+}
+
+/// Conventions keyed by alpha2
+static CONVENTIONS: Map<&'static str, Conventions> = phf_map! {
+    "AD" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "AE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "AF" => conventions!(Right, Metric, A4, Saturday, Thursday - Friday, Dmy, '.', ','),
+    "AG" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "AI" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "AL" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "AM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "AO" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "AQ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "AR" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "AS" => conventions!(Right, UsCustomary, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "AT" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "AU" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "AW" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "AX" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "AZ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "BA" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "BB" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "BD" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "BE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "BF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "BG" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "BH" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "BI" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "BJ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "BL" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "BM" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "BN" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "BO" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "BQ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "BR" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, ',', '.'),
+    "BS" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "BT" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Ymd, '.', ','),
+    "BV" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "BW" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "BY" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "BZ" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "CA" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Ymd, '.', ','),
+    "CC" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "CD" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "CF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "CG" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "CH" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', '\u{2019}'),
+    "CI" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "CK" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "CL" => conventions!(Right, Metric, Letter, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "CM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "CN" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Ymd, '.', ','),
+    "CO" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, ',', '.'),
+    "CR" => conventions!(Right, Metric, Letter, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "CU" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "CV" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "CW" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "CX" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "CY" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "CZ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "DE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "DJ" => conventions!(Right, Metric, A4, Saturday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "DK" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "DM" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "DO" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "DZ" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, ',', '.'),
+    "EC" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "EE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "EG" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "EH" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "ER" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "ES" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "ET" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "FI" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "FJ" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "FK" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "FM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Mdy, '.', ','),
+    "FO" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "FR" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "GA" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "GB" => conventions!(Left, Imperial, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GD" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "GF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "GG" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GH" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GI" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GL" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "GM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GN" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "GP" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "GQ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "GR" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "GS" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "GT" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "GU" => conventions!(Right, UsCustomary, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "GW" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "GY" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "HK" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "HM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "HN" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "HR" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "HT" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "HU" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Ymd, ',', '\u{a0}'),
+    "ID" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, ',', '.'),
+    "IE" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "IL" => conventions!(Right, Metric, A4, Sunday, Friday - Saturday, Dmy, '.', ','),
+    "IM" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "IN" => conventions!(Left, Metric, A4, Sunday, Sunday - Sunday, Dmy, '.', ','),
+    "IO" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "IQ" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "IR" => conventions!(Right, Metric, A4, Saturday, Friday - Friday, Ymd, '.', ','),
+    "IS" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "IT" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "JE" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "JM" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "JO" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "JP" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Ymd, '.', ','),
+    "KE" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "KG" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "KH" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "KI" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "KM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "KN" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "KP" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Ymd, '.', ','),
+    "KR" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Ymd, '.', ','),
+    "KW" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "KY" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "KZ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "LA" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, ',', '.'),
+    "LB" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "LC" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "LI" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', '\u{2019}'),
+    "LK" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "LR" => conventions!(Right, UsCustomary, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "LS" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "LT" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Ymd, ',', '\u{a0}'),
+    "LU" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "LV" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "LY" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "MA" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "MC" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "MD" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "ME" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "MF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "MG" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "MH" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "MK" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "ML" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "MM" => conventions!(Right, UsCustomary, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "MN" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Ymd, '.', ','),
+    "MO" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "MP" => conventions!(Right, UsCustomary, Letter, Monday, Saturday - Sunday, Mdy, '.', ','),
+    "MQ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "MR" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "MS" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "MT" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "MU" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "MV" => conventions!(Left, Metric, A4, Friday, Saturday - Sunday, Dmy, '.', ','),
+    "MW" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "MX" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "MY" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "MZ" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "NA" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "NC" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "NE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "NF" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "NG" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "NI" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "NL" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "NO" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "NP" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "NR" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "NU" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "NZ" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "OM" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "PA" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "PE" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "PF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "PG" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "PH" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "PK" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "PL" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "PM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "PN" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "PR" => conventions!(Right, UsCustomary, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "PS" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "PT" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "PW" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Mdy, '.', ','),
+    "PY" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Dmy, ',', '.'),
+    "QA" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "RE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "RO" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "RS" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "RU" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "RW" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "SA" => conventions!(Right, Metric, A4, Sunday, Friday - Saturday, Dmy, '.', ','),
+    "SB" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "SC" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "SD" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "SE" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Ymd, ',', '\u{a0}'),
+    "SG" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "SH" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "SI" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "SJ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "SK" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "SL" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "SM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "SN" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "SO" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "SR" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "SS" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "ST" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "SV" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "SX" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "SY" => conventions!(Right, Metric, A4, Saturday, Friday - Saturday, Dmy, '.', ','),
+    "SZ" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "TC" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "TD" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "TF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "TG" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "TH" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "TJ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "TK" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "TL" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "TM" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "TN" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "TO" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "TR" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "TT" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "TV" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "TW" => conventions!(Right, Metric, A4, Sunday, Saturday - Sunday, Ymd, '.', ','),
+    "TZ" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "UA" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "UG" => conventions!(Left, Metric, A4, Monday, Sunday - Sunday, Dmy, '.', ','),
+    "UM" => conventions!(Right, UsCustomary, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "US" => conventions!(Right, UsCustomary, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "UY" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "UZ" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "VA" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "VC" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "VE" => conventions!(Right, Metric, Letter, Sunday, Saturday - Sunday, Dmy, ',', '.'),
+    "VG" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "VI" => conventions!(Left, UsCustomary, Letter, Sunday, Saturday - Sunday, Mdy, '.', ','),
+    "VN" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '.'),
+    "VU" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "WF" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "WS" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+    "XK" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{a0}'),
+    "YE" => conventions!(Right, Metric, A4, Sunday, Friday - Saturday, Dmy, '.', ','),
+    "YT" => conventions!(Right, Metric, A4, Monday, Saturday - Sunday, Dmy, ',', '\u{202f}'),
+    "ZA" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Ymd, ',', '\u{a0}'),
+    "ZM" => conventions!(Left, Metric, A4, Monday, Saturday - Sunday, Dmy, '.', ','),
+    "ZW" => conventions!(Left, Metric, A4, Sunday, Saturday - Sunday, Dmy, '.', ','),
+};
+
+impl Country {
+    /// The everyday conventions used in this country
+    ///
+    /// ```
+    /// use celes::{Country, DateOrder, DrivingSide, MeasurementSystem, PaperSize, Weekday};
+    ///
+    /// let gb = Country::the_united_kingdom_of_great_britain_and_northern_ireland().conventions();
+    /// assert_eq!(DrivingSide::Left, gb.driving_side);
+    /// assert_eq!(MeasurementSystem::Imperial, gb.measurement_system);
+    /// assert_eq!(PaperSize::A4, gb.paper_size);
+    /// assert_eq!(Weekday::Monday, gb.first_day_of_week);
+    /// assert_eq!(DateOrder::Dmy, gb.date_order);
+    ///
+    /// let de = Country::germany().conventions();
+    /// assert_eq!(',', de.decimal_separator);
+    /// assert_eq!('.', de.grouping_separator);
+    /// ```
+    #[must_use]
+    pub fn conventions(&self) -> Conventions {
+        CONVENTIONS
+            .get(self.alpha2())
+            .copied()
+            .unwrap_or(conventions!(
+                Right,
+                Metric,
+                A4,
+                Monday,
+                Saturday - Sunday,
+                Dmy,
+                '.',
+                ','
+            ))
+    }
+}
@@ -0,0 +1,206 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Compare two dataset snapshots to find what changed between releases
+//!
+//! A snapshot is the JSON export of every country with its codes, name and aliases.
+//! Entries are matched by their numeric value so a change of numeric code shows up
+//! as a removal and an addition.
+//!
+//! ```
+//! use celes::diff::{Difference, Snapshot, diff};
+//!
+//! let old = Snapshot::from_json(r#"[
+//!     {"code": "792", "value": 792, "alpha2": "TR", "alpha3": "TUR", "long_name": "Turkey", "aliases": ["Turkey"]}
+//! ]"#).expect("valid snapshot");
+//! let new = Snapshot::from_json(r#"[
+//!     {"code": "792", "value": 792, "alpha2": "TR", "alpha3": "TUR", "long_name": "Türkiye", "aliases": ["Turkey", "Turkiye"]}
+//! ]"#).expect("valid snapshot");
+//!
+//! let changes = diff(&old, &new);
+//! assert_eq!(2, changes.len());
+//! assert!(matches!(&changes[0], Difference::Renamed { new, .. } if new == "Türkiye"));
+//! assert!(matches!(&changes[1], Difference::AliasAdded { alias, .. } if alias == "Turkiye"));
+//! ```
+
+use crate::{Country, LookupTable};
+use serde::{Deserialize, Serialize};
+
+/// A country as stored in a snapshot
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Record {
+    /// The three digit code
+    pub code: String,
+    /// The numeric code as an integer
+    pub value: usize,
+    /// The alpha2 code
+    pub alpha2: String,
+    /// The alpha3 code
+    pub alpha3: String,
+    /// The official state name
+    pub long_name: String,
+    /// Common aliases
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl From<Country> for Record {
+    fn from(country: Country) -> Self {
+        Self {
+            code: country.code().to_string(),
+            value: country.value(),
+            alpha2: country.alpha2().to_string(),
+            alpha3: country.alpha3().to_string(),
+            long_name: country.long_name().to_string(),
+            aliases: country.aliases().iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// Every country in a dataset
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Snapshot {
+    /// The countries ordered by numeric value
+    pub countries: Vec<Record>,
+}
+
+impl Snapshot {
+    /// A snapshot of the compiled-in dataset
+    #[must_use]
+    pub fn current() -> Self {
+        Self::from(
+            Country::all()
+                .iter()
+                .copied()
+                .map(Record::from)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Parse a snapshot from its JSON export
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON isn't an array of records.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str::<Vec<Record>>(json).map(Self::from)
+    }
+
+    /// Export this snapshot as JSON
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl From<Vec<Record>> for Snapshot {
+    fn from(mut countries: Vec<Record>) -> Self {
+        countries.sort_by_key(|r| r.value);
+        Self { countries }
+    }
+}
+
+/// A single change between two snapshots
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Difference {
+    /// A country only in the new snapshot
+    Added {
+        /// The new country
+        country: Record,
+    },
+    /// A country only in the old snapshot
+    Removed {
+        /// The removed country
+        country: Record,
+    },
+    /// The official name changed
+    Renamed {
+        /// The numeric value of the country
+        value: usize,
+        /// The previous name
+        old: String,
+        /// The current name
+        new: String,
+    },
+    /// The alpha2 or alpha3 code changed
+    Recoded {
+        /// The numeric value of the country
+        value: usize,
+        /// The previous alpha2 and alpha3 codes
+        old: (String, String),
+        /// The current alpha2 and alpha3 codes
+        new: (String, String),
+    },
+    /// An alias was added
+    AliasAdded {
+        /// The numeric value of the country
+        value: usize,
+        /// The new alias
+        alias: String,
+    },
+    /// An alias was removed
+    AliasRemoved {
+        /// The numeric value of the country
+        value: usize,
+        /// The removed alias
+        alias: String,
+    },
+}
+
+/// Compare two snapshots. Changes are ordered by numeric value with additions last.
+#[must_use]
+pub fn diff(old: &Snapshot, new: &Snapshot) -> Vec<Difference> {
+    let mut changes = Vec::new();
+    for before in &old.countries {
+        let Some(after) = new.countries.iter().find(|r| r.value == before.value) else {
+            changes.push(Difference::Removed {
+                country: before.clone(),
+            });
+            continue;
+        };
+        if before.long_name != after.long_name {
+            changes.push(Difference::Renamed {
+                value: before.value,
+                old: before.long_name.clone(),
+                new: after.long_name.clone(),
+            });
+        }
+        if before.alpha2 != after.alpha2 || before.alpha3 != after.alpha3 {
+            changes.push(Difference::Recoded {
+                value: before.value,
+                old: (before.alpha2.clone(), before.alpha3.clone()),
+                new: (after.alpha2.clone(), after.alpha3.clone()),
+            });
+        }
+        changes.extend(
+            before
+                .aliases
+                .iter()
+                .filter(|a| !after.aliases.contains(a))
+                .map(|alias| Difference::AliasRemoved {
+                    value: before.value,
+                    alias: alias.clone(),
+                }),
+        );
+        changes.extend(
+            after
+                .aliases
+                .iter()
+                .filter(|a| !before.aliases.contains(a))
+                .map(|alias| Difference::AliasAdded {
+                    value: after.value,
+                    alias: alias.clone(),
+                }),
+        );
+    }
+    changes.extend(
+        new.countries
+            .iter()
+            .filter(|r| old.countries.iter().all(|o| o.value != r.value))
+            .map(|r| Difference::Added { country: r.clone() }),
+    );
+    changes
+}
@@ -0,0 +1,459 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! The history of changes to ISO 3166-1 as published by the ISO 3166 Maintenance Agency
+//!
+//! Changes are recorded from ISO 3166-1:1997 onward along with earlier renames
+//! that are still in common use. Queries for earlier dates are otherwise answered
+//! using the list as it stood in 1997.
+
+use crate::Country;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// The effective date of the most recent change reflected in the dataset
+pub const DATA_VERSION: &str = "2022-06-01";
+
+/// A calendar date used for the effective date of changes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Create a date or `None` if the month or day is out of range
+    ///
+    /// ```
+    /// use celes::Date;
+    ///
+    /// assert!(Date::new(2010, 12, 15).is_some());
+    /// assert!(Date::new(2023, 2, 29).is_none());
+    /// ```
+    #[must_use]
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.is_multiple_of(4)
+                && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// The year
+    #[must_use]
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month from 1 to 12
+    #[must_use]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month starting at 1
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = &'static str;
+
+    /// Parse a date in the form `YYYY-MM-DD`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err("invalid date");
+        }
+        let year = s[..4].parse().map_err(|_| "invalid date")?;
+        let month = s[5..7].parse().map_err(|_| "invalid date")?;
+        let day = s[8..].parse().map_err(|_| "invalid date")?;
+        Self::new(year, month, day).ok_or("invalid date")
+    }
+}
+
+/// The kind of change made to an entry
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// The entry was added
+    Added,
+    /// The entry was deleted
+    Removed,
+    /// The entry's name changed. Contains the previous name.
+    Renamed {
+        /// The name before the change
+        old: &'static str,
+    },
+}
+
+/// A change to ISO 3166-1 and the date it took effect
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Change {
+    /// The date the change took effect
+    pub date: Date,
+    /// The alpha2 code of the entry
+    pub alpha2: &'static str,
+    /// The alpha3 code of the entry
+    pub alpha3: &'static str,
+    /// The numeric code of the entry
+    pub value: usize,
+    /// The name of the entry. For renames this is the new name.
+    pub name: &'static str,
+    /// What changed
+    pub kind: ChangeKind,
+}
+
+const fn ymd(year: u16, month: u8, day: u8) -> Date {
+    match Date::new(year, month, day) {
+        Some(date) => date,
+        None => panic!("invalid date"),
+    }
+}
+
+macro_rules! history {
+    ($($y:literal-$m:literal-$d:literal $kind:ident$(($old:literal))? $alpha2:literal $alpha3:literal $value:literal $name:literal;)*) => {
+        &[$(
+            Change {
+                date: ymd($y, $m, $d),
+                alpha2: $alpha2,
+                alpha3: $alpha3,
+                value: $value,
+                name: $name,
+                kind: ChangeKind::$kind$({ old: $old })?,
+            },
+        )*]
+    };
+}
+
+/// Every recorded change ordered by effective date
+pub const HISTORY: &[Change] = history! {
+    1989-12-5 Removed "BU" "BUR" 104 "Burma";
+    1989-12-5 Added "MM" "MMR" 104 "Myanmar";
+    1997-7-14 Removed "FX" "FXX" 249 "France, Metropolitan";
+    1997-7-14 Removed "ZR" "ZAR" 180 "Zaire";
+    1997-7-14 Added "CD" "COD" 180 "The Democratic Republic Of The Congo";
+    2002-5-20 Removed "TP" "TMP" 626 "East Timor";
+    2002-5-20 Added "TL" "TLS" 626 "Timor Leste";
+    2003-7-23 Removed "YU" "YUG" 891 "Yugoslavia";
+    2003-7-23 Added "CS" "SCG" 891 "Serbia And Montenegro";
+    2004-2-13 Added "AX" "ALA" 248 "Aland Islands";
+    2006-3-29 Added "GG" "GGY" 831 "Guernsey";
+    2006-3-29 Added "IM" "IMN" 833 "Isle Of Man";
+    2006-3-29 Added "JE" "JEY" 832 "Jersey";
+    2006-9-26 Removed "CS" "SCG" 891 "Serbia And Montenegro";
+    2006-9-26 Added "ME" "MNE" 499 "Montenegro";
+    2006-9-26 Added "RS" "SRB" 688 "Serbia";
+    2007-9-21 Added "BL" "BLM" 652 "Saint Barthelemy";
+    2007-9-21 Added "MF" "MAF" 663 "French Part Saint Martin";
+    2010-12-15 Removed "AN" "ANT" 530 "Netherlands Antilles";
+    2010-12-15 Added "BQ" "BES" 535 "Bonaire";
+    2010-12-15 Added "CW" "CUW" 531 "Curacao";
+    2010-12-15 Added "SX" "SXM" 534 "Dutch Part Sint Maarten";
+    2011-8-9 Removed "SD" "SDN" 736 "Sudan";
+    2011-8-9 Added "SD" "SDN" 729 "The Sudan";
+    2011-8-9 Added "SS" "SSD" 728 "South Sudan";
+    2013-10-24 Renamed("Cape Verde") "CV" "CPV" 132 "Cabo Verde";
+    2016-7-11 Renamed("Czech Republic") "CZ" "CZE" 203 "Czechia";
+    2018-11-26 Renamed("Swaziland") "SZ" "SWZ" 748 "Eswatini";
+    2019-2-14 Renamed("The Former Yugoslav Republic Of Macedonia") "MK" "MKD" 807 "Republic Of North Macedonia";
+    2022-6-1 Renamed("Turkey") "TR" "TUR" 792 "Türkiye";
+};
+
+/// An ISO 3166-1 entry as it stood over a period of time
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CodeEntry {
+    /// The alpha2 code of the entry
+    pub alpha2: &'static str,
+    /// The alpha3 code of the entry
+    pub alpha3: &'static str,
+    /// The numeric code of the entry
+    pub value: usize,
+    /// The name of the entry during the period
+    pub name: &'static str,
+    /// The first date the entry was valid or `None` if it predates the recorded history
+    pub valid_from: Option<Date>,
+    /// The date the entry was removed or renamed or `None` if it is still in effect
+    pub valid_until: Option<Date>,
+}
+
+impl CodeEntry {
+    /// True if the entry was valid on `date`
+    #[must_use]
+    pub fn is_valid_on(&self, date: Date) -> bool {
+        self.valid_from.is_none_or(|from| from <= date)
+            && self.valid_until.is_none_or(|until| date < until)
+    }
+
+    /// The current country with the same codes or `None` if the entry was removed
+    #[must_use]
+    pub fn country(&self) -> Option<Country> {
+        Country::from_value(self.value)
+            .ok()
+            .filter(|c| c.alpha2() == self.alpha2)
+    }
+
+    fn matches(&self, code: &str) -> bool {
+        self.alpha2.eq_ignore_ascii_case(code)
+            || self.alpha3.eq_ignore_ascii_case(code)
+            || code.parse::<usize>().is_ok_and(|v| v == self.value)
+            || strip_eq(self.name, code)
+    }
+
+    fn current(country: Country, date: Date) -> Option<Self> {
+        let mut entry = Self {
+            alpha2: country.alpha2(),
+            alpha3: country.alpha3(),
+            value: country.value(),
+            name: country.long_name(),
+            valid_from: None,
+            valid_until: None,
+        };
+        for change in HISTORY
+            .iter()
+            .filter(|c| c.alpha2 == country.alpha2() && c.value == country.value())
+        {
+            match change.kind {
+                ChangeKind::Renamed { old } if date < change.date => {
+                    entry.name = old;
+                    entry.valid_until = Some(change.date);
+                    break;
+                }
+                ChangeKind::Added | ChangeKind::Renamed { .. } => {
+                    entry.valid_from = Some(change.date);
+                }
+                ChangeKind::Removed => {}
+            }
+        }
+        Some(entry).filter(|e| e.is_valid_on(date))
+    }
+
+    fn former(removed: &Change) -> Self {
+        let valid_from = HISTORY
+            .iter()
+            .find(|c| {
+                c.kind == ChangeKind::Added
+                    && c.alpha2 == removed.alpha2
+                    && c.value == removed.value
+                    && c.date < removed.date
+            })
+            .map(|c| c.date);
+        Self {
+            alpha2: removed.alpha2,
+            alpha3: removed.alpha3,
+            value: removed.value,
+            name: removed.name,
+            valid_from,
+            valid_until: Some(removed.date),
+        }
+    }
+}
+
+fn strip_eq(name: &str, code: &str) -> bool {
+    let mut a = name.chars().filter(|c| *c != ' ');
+    let mut b = code.chars().filter(|c| *c != ' ' && *c != '_');
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return true,
+            (Some(x), Some(y)) if x.to_lowercase().eq(y.to_lowercase()) => {}
+            _ => return false,
+        }
+    }
+}
+
+impl Country {
+    /// Every ISO 3166-1 entry as it stood on `date` including entries that have since
+    /// been removed. Names are the ones in effect on that date.
+    ///
+    /// ```
+    /// use celes::{Country, Date};
+    ///
+    /// let date = Date::new(2005, 1, 1).expect("valid date");
+    /// assert!(Country::as_of(date).all(|e| e.alpha2 != "YU"));
+    /// assert!(Country::as_of(date).any(|e| e.alpha2 == "CS"));
+    /// assert!(Country::as_of(date).any(|e| e.alpha2 == "AN"));
+    /// assert!(Country::as_of(date).all(|e| e.alpha2 != "SS"));
+    /// ```
+    pub fn as_of(date: Date) -> impl Iterator<Item = CodeEntry> {
+        Self::all()
+            .iter()
+            .filter_map(move |country| CodeEntry::current(*country, date))
+            .chain(
+                HISTORY
+                    .iter()
+                    .filter(|c| c.kind == ChangeKind::Removed)
+                    .map(CodeEntry::former)
+                    .filter(move |e| e.is_valid_on(date)),
+            )
+    }
+
+    /// Interpret a code or name as it would have been on `date`. Accepts the same
+    /// input as `Country::from_str` plus codes and names of removed entries.
+    /// This is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"unknown value"` if nothing matched on that date.
+    ///
+    /// ```
+    /// use celes::{Country, Date};
+    ///
+    /// let date = Date::new(2008, 6, 1).expect("valid date");
+    /// let entry = Country::from_str_as_of("AN", date).expect("valid in 2008");
+    /// assert_eq!("Netherlands Antilles", entry.name);
+    /// assert!(entry.country().is_none());
+    ///
+    /// let date = Date::new(2020, 1, 1).expect("valid date");
+    /// let entry = Country::from_str_as_of("TR", date).expect("valid in 2020");
+    /// assert_eq!("Turkey", entry.name);
+    /// assert_eq!(Some(Country::turkiye()), entry.country());
+    ///
+    /// assert!(Country::from_str_as_of("AN", Date::new(2020, 1, 1).expect("valid date")).is_err());
+    /// ```
+    pub fn from_str_as_of<A: AsRef<str>>(code: A, date: Date) -> Result<CodeEntry, &'static str> {
+        let code = code.as_ref();
+        if let Some(entry) = Self::from_str(code)
+            .ok()
+            .and_then(|country| CodeEntry::current(country, date))
+        {
+            return Ok(entry);
+        }
+        Self::as_of(date)
+            .find(|e| e.matches(code))
+            .ok_or("unknown value")
+    }
+}
+
+/// Aliases that refer to a country by a name it no longer uses, with the
+/// numeric code of the country
+const SUPERSEDED_ALIASES: &[(&str, usize)] = &[
+    ("burma", 104),
+    ("capeverde", 132),
+    ("czechrepublic", 203),
+    ("easttimor", 626),
+    ("macedonia", 807),
+    ("swaziland", 748),
+    ("turkey", 792),
+];
+
+/// A note that a country was renamed
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Renamed {
+    /// The name before the change
+    pub old: &'static str,
+    /// The name after the change
+    pub new: &'static str,
+    /// The date the change took effect
+    pub since: Date,
+}
+
+impl Country {
+    /// The most recent rename of this country, if any. A change of codes that kept
+    /// the numeric code, like Burma to Myanmar, counts as a rename.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let renamed = Country::eswatini().renamed().expect("Swaziland was renamed");
+    /// assert_eq!("Swaziland", renamed.old);
+    /// assert_eq!("Eswatini", renamed.new);
+    /// assert!(Country::france().renamed().is_none());
+    /// ```
+    #[must_use]
+    pub fn renamed(&self) -> Option<Renamed> {
+        HISTORY.iter().rev().find_map(|change| {
+            if change.value != self.value() {
+                return None;
+            }
+            match change.kind {
+                ChangeKind::Renamed { old } => Some(Renamed {
+                    old,
+                    new: change.name,
+                    since: change.date,
+                }),
+                ChangeKind::Added => HISTORY
+                    .iter()
+                    .find(|c| {
+                        c.kind == ChangeKind::Removed
+                            && c.value == change.value
+                            && c.date == change.date
+                    })
+                    .map(|removed| Renamed {
+                        old: removed.name,
+                        new: change.name,
+                        since: change.date,
+                    }),
+                ChangeKind::Removed => None,
+            }
+        })
+    }
+
+    /// Like `Country::from_str` but also reports when the input used a name the
+    /// country no longer goes by, such as "Swaziland", "Burma" or "Turkey".
+    /// This is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"unknown value"` if the input doesn't match any country.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let (country, renamed) = Country::from_str_renamed("Swaziland").expect("old name");
+    /// assert_eq!(Country::eswatini(), country);
+    /// let renamed = renamed.expect("Swaziland is outdated");
+    /// assert_eq!("Eswatini", renamed.new);
+    /// assert_eq!("2018-11-26", renamed.since.to_string());
+    ///
+    /// let (country, renamed) = Country::from_str_renamed("Eswatini").expect("current name");
+    /// assert_eq!(Country::eswatini(), country);
+    /// assert!(renamed.is_none());
+    /// ```
+    pub fn from_str_renamed<A: AsRef<str>>(
+        name: A,
+    ) -> Result<(Self, Option<Renamed>), &'static str> {
+        let name = name.as_ref();
+        if let Ok(country) = Self::from_str(name) {
+            let outdated = country.is_outdated_name(name);
+            return Ok((country, country.renamed().filter(|_| outdated)));
+        }
+        Self::all()
+            .iter()
+            .find_map(|country| {
+                country
+                    .renamed()
+                    .filter(|r| strip_eq(r.old, name))
+                    .map(|r| (*country, Some(r)))
+            })
+            .ok_or("unknown value")
+    }
+
+    /// True if `name` is a name this country no longer goes by
+    pub(crate) fn is_outdated_name(self, name: &str) -> bool {
+        SUPERSEDED_ALIASES
+            .iter()
+            .any(|(alias, value)| *value == self.value() && strip_eq(alias, name))
+            || self.renamed().is_some_and(|r| strip_eq(r.old, name))
+    }
+}
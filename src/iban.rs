@@ -0,0 +1,367 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! International Bank Account Numbers as specified by ISO 13616
+//!
+//! The BBAN structure uses the notation from the SWIFT IBAN registry where each
+//! group is a length followed by `!` and a character type:
+//!
+//! - `n` - digits
+//! - `a` - upper case letters
+//! - `c` - upper case letters or digits
+//! - `e` - spaces
+//!
+//! ```
+//! use celes::{Country, iban};
+//!
+//! let (country, iban) = iban::validate("GB82 WEST 1234 5698 7654 32").unwrap();
+//! assert_eq!(Country::the_united_kingdom_of_great_britain_and_northern_ireland(), country);
+//! assert_eq!("GB82WEST12345698765432", iban.as_str());
+//! assert_eq!("GB82 WEST 1234 5698 7654 32", format!("{:#}", iban));
+//! ```
+
+use crate::Country;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use phf::{Map, phf_map};
+
+/// The maximum length of an IBAN in the electronic format
+const MAX_IBAN_LEN: usize = 34;
+
+/// The IBAN structure used by a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IbanFormat {
+    /// The total length of the IBAN in the electronic format
+    pub length: usize,
+    /// The BBAN structure in SWIFT registry notation, e.g. `8!n10!n`
+    pub bban: &'static str,
+    /// True if the country participates in the Single Euro Payments Area
+    pub sepa: bool,
+}
+
+impl IbanFormat {
+    const fn new(length: usize, bban: &'static str, sepa: bool) -> Self {
+        Self { length, bban, sepa }
+    }
+
+    /// True if `bban` matches the BBAN structure of this format
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let format = Country::germany().iban_format().unwrap();
+    /// assert!(format.validate_bban("370400440532013000"));
+    /// assert!(!format.validate_bban("37040044053201300A"));
+    /// ```
+    #[must_use]
+    pub fn validate_bban(&self, bban: &str) -> bool {
+        let mut input = bban.bytes();
+        let mut structure = self.bban.bytes();
+        let mut count = 0usize;
+        while let Some(b) = structure.next() {
+            match b {
+                b'0'..=b'9' => count = count * 10 + usize::from(b - b'0'),
+                b'!' => {
+                    let Some(kind) = structure.next() else {
+                        return false;
+                    };
+                    for _ in 0..count {
+                        let valid = match (kind, input.next()) {
+                            (b'n', Some(c)) => c.is_ascii_digit(),
+                            (b'a', Some(c)) => c.is_ascii_uppercase(),
+                            (b'c', Some(c)) => c.is_ascii_uppercase() || c.is_ascii_digit(),
+                            (b'e', Some(c)) => c == b' ',
+                            _ => false,
+                        };
+                        if !valid {
+                            return false;
+                        }
+                    }
+                    count = 0;
+                }
+                _ => return false,
+            }
+        }
+        input.next().is_none()
+    }
+}
+
+/// The errors that can occur when validating an IBAN
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IbanError {
+    /// The IBAN contains characters other than letters, digits and spaces
+    InvalidCharacter,
+    /// The first two letters are not a known alpha2 code
+    UnknownCountry,
+    /// The country does not use IBANs
+    UnsupportedCountry,
+    /// The length does not match the length used by the country
+    InvalidLength {
+        /// The length used by the country
+        expected: usize,
+        /// The length of the input
+        actual: usize,
+    },
+    /// The check digits or BBAN do not match the structure used by the country
+    InvalidFormat,
+    /// The mod-97 checksum failed
+    InvalidChecksum,
+}
+
+impl Display for IbanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::InvalidCharacter => write!(f, "invalid character"),
+            Self::UnknownCountry => write!(f, "unknown country"),
+            Self::UnsupportedCountry => write!(f, "country does not use IBANs"),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length, expected {expected} but found {actual}")
+            }
+            Self::InvalidFormat => write!(f, "invalid format"),
+            Self::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+impl core::error::Error for IbanError {}
+
+/// A validated IBAN in the electronic format stored inline without heap allocation
+///
+/// `Display` writes the electronic format. The alternate flag `{:#}` writes the
+/// print format with the characters in groups of four.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Iban {
+    buf: [u8; MAX_IBAN_LEN],
+    len: usize,
+}
+
+impl Iban {
+    /// The IBAN in the electronic format
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only ASCII bytes are ever written to the buffer
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// The two letter country code
+    #[must_use]
+    pub fn country_code(&self) -> &str {
+        &self.as_str()[..2]
+    }
+
+    /// The two check digits
+    #[must_use]
+    pub fn check_digits(&self) -> &str {
+        &self.as_str()[2..4]
+    }
+
+    /// The Basic Bank Account Number
+    #[must_use]
+    pub fn bban(&self) -> &str {
+        &self.as_str()[4..]
+    }
+}
+
+impl AsRef<str> for Iban {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for Iban {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Iban({})", self.as_str())
+    }
+}
+
+impl Display for Iban {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !f.alternate() {
+            return f.write_str(self.as_str());
+        }
+        for (i, group) in self.buf[..self.len].chunks(4).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            // SAFETY: only ASCII bytes are ever written to the buffer
+            f.write_str(unsafe { core::str::from_utf8_unchecked(group) })?;
+        }
+        Ok(())
+    }
+}
+
+/// Validate an IBAN in either the electronic or print format
+///
+/// The country is resolved from the first two letters, then the length,
+/// BBAN structure and mod-97 checksum are checked.
+///
+/// # Errors
+///
+/// Returns an [`IbanError`] describing the first check that failed.
+///
+/// ```
+/// use celes::{Country, iban::{self, IbanError}};
+///
+/// let (country, _) = iban::validate("DE89370400440532013000").unwrap();
+/// assert_eq!(Country::germany(), country);
+///
+/// assert_eq!(Err(IbanError::InvalidChecksum), iban::validate("DE88370400440532013000"));
+/// assert_eq!(Err(IbanError::UnknownCountry), iban::validate("ZZ89370400440532013000"));
+/// assert_eq!(Err(IbanError::UnsupportedCountry), iban::validate("US89370400440532013000"));
+/// ```
+pub fn validate(iban: &str) -> Result<(Country, Iban), IbanError> {
+    let compact = || iban.trim().bytes().filter(|b| *b != b' ');
+    if compact().any(|b| !b.is_ascii_alphanumeric()) {
+        return Err(IbanError::InvalidCharacter);
+    }
+    let actual = compact().count();
+    let mut prefix = compact().map(|b| b.to_ascii_uppercase());
+    let (Some(a), Some(b)) = (prefix.next(), prefix.next()) else {
+        return Err(IbanError::UnknownCountry);
+    };
+    let country = Country::from_alpha2_bytes([a, b]).map_err(|_| IbanError::UnknownCountry)?;
+    let format = country.iban_format().ok_or(IbanError::UnsupportedCountry)?;
+    if actual != format.length {
+        return Err(IbanError::InvalidLength {
+            expected: format.length,
+            actual,
+        });
+    }
+    let mut out = Iban {
+        buf: [0u8; MAX_IBAN_LEN],
+        len: actual,
+    };
+    for (dst, b) in out.buf.iter_mut().zip(compact()) {
+        *dst = b.to_ascii_uppercase();
+    }
+    if !out.check_digits().bytes().all(|b| b.is_ascii_digit()) || !format.validate_bban(out.bban())
+    {
+        return Err(IbanError::InvalidFormat);
+    }
+    let remainder = out
+        .bban()
+        .bytes()
+        .chain(out.as_str()[..4].bytes())
+        .fold(0u32, |acc, b| {
+            if b.is_ascii_digit() {
+                (acc * 10 + u32::from(b - b'0')) % 97
+            } else {
+                (acc * 100 + u32::from(b - b'A') + 10) % 97
+            }
+        });
+    if remainder != 1 {
+        return Err(IbanError::InvalidChecksum);
+    }
+    Ok((country, out))
+}
+
+/// IBAN formats keyed by alpha2 as published in the SWIFT IBAN registry
+static IBAN_FORMATS: Map<&'static str, IbanFormat> = phf_map! {
+    "AD" => IbanFormat::new(24, "4!n4!n12!c", true),
+    "AE" => IbanFormat::new(23, "3!n16!n", false),
+    "AL" => IbanFormat::new(28, "8!n16!c", true),
+    "AT" => IbanFormat::new(20, "5!n11!n", true),
+    "AZ" => IbanFormat::new(28, "4!a20!c", false),
+    "BA" => IbanFormat::new(20, "3!n3!n8!n2!n", false),
+    "BE" => IbanFormat::new(16, "3!n7!n2!n", true),
+    "BG" => IbanFormat::new(22, "4!a4!n2!n8!c", true),
+    "BH" => IbanFormat::new(22, "4!a14!c", false),
+    "BI" => IbanFormat::new(27, "5!n5!n11!n2!n", false),
+    "BR" => IbanFormat::new(29, "8!n5!n10!n1!a1!c", false),
+    "BY" => IbanFormat::new(28, "4!c4!n16!c", false),
+    "CH" => IbanFormat::new(21, "5!n12!c", true),
+    "CR" => IbanFormat::new(22, "4!n14!n", false),
+    "CY" => IbanFormat::new(28, "3!n5!n16!c", true),
+    "CZ" => IbanFormat::new(24, "4!n6!n10!n", true),
+    "DE" => IbanFormat::new(22, "8!n10!n", true),
+    "DJ" => IbanFormat::new(27, "5!n5!n11!n2!n", false),
+    "DK" => IbanFormat::new(18, "4!n9!n1!n", true),
+    "DO" => IbanFormat::new(28, "4!c20!n", false),
+    "EE" => IbanFormat::new(20, "2!n2!n11!n1!n", true),
+    "EG" => IbanFormat::new(29, "4!n4!n17!n", false),
+    "ES" => IbanFormat::new(24, "4!n4!n1!n1!n10!n", true),
+    "FI" => IbanFormat::new(18, "3!n11!n", true),
+    "FK" => IbanFormat::new(18, "2!a12!n", false),
+    "FO" => IbanFormat::new(18, "4!n9!n1!n", false),
+    "FR" => IbanFormat::new(27, "5!n5!n11!c2!n", true),
+    "GB" => IbanFormat::new(22, "4!a6!n8!n", true),
+    "GE" => IbanFormat::new(22, "2!a16!n", false),
+    "GI" => IbanFormat::new(23, "4!a15!c", true),
+    "GL" => IbanFormat::new(18, "4!n9!n1!n", false),
+    "GR" => IbanFormat::new(27, "3!n4!n16!c", true),
+    "GT" => IbanFormat::new(28, "4!c20!c", false),
+    "HR" => IbanFormat::new(21, "7!n10!n", true),
+    "HU" => IbanFormat::new(28, "3!n4!n1!n15!n1!n", true),
+    "IE" => IbanFormat::new(22, "4!a6!n8!n", true),
+    "IL" => IbanFormat::new(23, "3!n3!n13!n", false),
+    "IQ" => IbanFormat::new(23, "4!a3!n12!n", false),
+    "IS" => IbanFormat::new(26, "4!n2!n6!n10!n", true),
+    "IT" => IbanFormat::new(27, "1!a5!n5!n12!c", true),
+    "JO" => IbanFormat::new(30, "4!a4!n18!c", false),
+    "KW" => IbanFormat::new(30, "4!a22!c", false),
+    "KZ" => IbanFormat::new(20, "3!n13!c", false),
+    "LB" => IbanFormat::new(28, "4!n20!c", false),
+    "LC" => IbanFormat::new(32, "4!a24!c", false),
+    "LI" => IbanFormat::new(21, "5!n12!c", true),
+    "LT" => IbanFormat::new(20, "5!n11!n", true),
+    "LU" => IbanFormat::new(20, "3!n13!c", true),
+    "LV" => IbanFormat::new(21, "4!a13!c", true),
+    "LY" => IbanFormat::new(25, "3!n3!n15!n", false),
+    "MC" => IbanFormat::new(27, "5!n5!n11!c2!n", true),
+    "MD" => IbanFormat::new(24, "2!c18!c", true),
+    "ME" => IbanFormat::new(22, "3!n13!n2!n", true),
+    "MK" => IbanFormat::new(19, "3!n10!c2!n", true),
+    "MN" => IbanFormat::new(20, "4!n12!n", false),
+    "MR" => IbanFormat::new(27, "5!n5!n11!n2!n", false),
+    "MT" => IbanFormat::new(31, "4!a5!n18!c", true),
+    "MU" => IbanFormat::new(30, "4!a2!n2!n12!n3!n3!a", false),
+    "NI" => IbanFormat::new(28, "4!a20!n", false),
+    "NL" => IbanFormat::new(18, "4!a10!n", true),
+    "NO" => IbanFormat::new(15, "4!n6!n1!n", true),
+    "OM" => IbanFormat::new(23, "3!n16!c", false),
+    "PK" => IbanFormat::new(24, "4!a16!c", false),
+    "PL" => IbanFormat::new(28, "8!n16!n", true),
+    "PS" => IbanFormat::new(29, "4!a21!c", false),
+    "PT" => IbanFormat::new(25, "4!n4!n11!n2!n", true),
+    "QA" => IbanFormat::new(29, "4!a21!c", false),
+    "RO" => IbanFormat::new(24, "4!a16!c", true),
+    "RS" => IbanFormat::new(22, "3!n13!n2!n", false),
+    "RU" => IbanFormat::new(33, "9!n5!n15!c", false),
+    "SA" => IbanFormat::new(24, "2!n18!c", false),
+    "SC" => IbanFormat::new(31, "4!a2!n2!n16!n3!a", false),
+    "SD" => IbanFormat::new(18, "2!n12!n", false),
+    "SE" => IbanFormat::new(24, "3!n16!n1!n", true),
+    "SI" => IbanFormat::new(19, "5!n8!n2!n", true),
+    "SK" => IbanFormat::new(24, "4!n6!n10!n", true),
+    "SM" => IbanFormat::new(27, "1!a5!n5!n12!c", true),
+    "SO" => IbanFormat::new(23, "4!n3!n12!n", false),
+    "ST" => IbanFormat::new(25, "4!n4!n11!n2!n", false),
+    "SV" => IbanFormat::new(28, "4!a20!n", false),
+    "TL" => IbanFormat::new(23, "3!n14!n2!n", false),
+    "TN" => IbanFormat::new(24, "2!n3!n13!n2!n", false),
+    "TR" => IbanFormat::new(26, "5!n1!n16!c", false),
+    "UA" => IbanFormat::new(29, "6!n19!c", false),
+    "VA" => IbanFormat::new(22, "3!n15!n", true),
+    "VG" => IbanFormat::new(24, "4!a16!n", false),
+    "XK" => IbanFormat::new(20, "4!n10!n2!n", false),
+    "YE" => IbanFormat::new(30, "4!a4!n18!c", false),
+};
+
+impl Country {
+    /// The IBAN structure used by this country or `None` if the country does not use IBANs
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let format = Country::germany().iban_format().unwrap();
+    /// assert_eq!(22, format.length);
+    /// assert_eq!("8!n10!n", format.bban);
+    /// assert!(format.sepa);
+    ///
+    /// assert!(Country::the_united_states_of_america().iban_format().is_none());
+    /// ```
+    #[must_use]
+    pub fn iban_format(&self) -> Option<IbanFormat> {
+        IBAN_FORMATS.get(self.alpha2()).copied()
+    }
+}
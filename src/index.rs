@@ -0,0 +1,290 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Direct-index tables for code lookups
+//!
+//! Alpha2 codes index a 26×26 table, alpha3 codes a 26×26×26 table and numeric
+//! codes a 0..=999 table. Each slot holds the position of the country in
+//! [`RECORDS`] plus one, with zero marking an unassigned code. The tables are
+//! built at compile time from the compiled-in records.
+
+use crate::{Country, RECORDS};
+
+/// Every compiled-in country in the order of `Country::get_countries`
+pub(crate) static COUNTRIES: [Country; Country::COUNT] = Country::get_countries();
+
+const _: () = assert!(RECORDS.len() < u8::MAX as usize);
+
+static ALPHA2: [u8; 26 * 26] = build_alpha2();
+static ALPHA3: [u8; 26 * 26 * 26] = build_alpha3();
+static NUMERIC: [u8; 1000] = build_numeric();
+
+/// Maps an ASCII letter of either case to `0..26` and anything else to 26 or more
+const fn letter(b: u8) -> usize {
+    (b | 0x20).wrapping_sub(b'a') as usize
+}
+
+/// Maps an ASCII digit to `0..10` and anything else to 10 or more
+const fn digit(b: u8) -> usize {
+    b.wrapping_sub(b'0') as usize
+}
+
+#[allow(deprecated, clippy::cast_possible_truncation)]
+const fn build_alpha2() -> [u8; 26 * 26] {
+    let mut table = [0; 26 * 26];
+    let mut i = 0;
+    while i < RECORDS.len() {
+        let b = RECORDS[i].alpha2.as_bytes();
+        table[letter(b[0]) * 26 + letter(b[1])] = i as u8 + 1;
+        i += 1;
+    }
+    table
+}
+
+// Only ever evaluated at compile time
+#[allow(
+    deprecated,
+    clippy::cast_possible_truncation,
+    clippy::large_stack_arrays
+)]
+const fn build_alpha3() -> [u8; 26 * 26 * 26] {
+    let mut table = [0; 26 * 26 * 26];
+    let mut i = 0;
+    while i < RECORDS.len() {
+        let b = RECORDS[i].alpha3.as_bytes();
+        table[(letter(b[0]) * 26 + letter(b[1])) * 26 + letter(b[2])] = i as u8 + 1;
+        i += 1;
+    }
+    table
+}
+
+#[allow(deprecated, clippy::cast_possible_truncation)]
+const fn build_numeric() -> [u8; 1000] {
+    let mut table = [0; 1000];
+    let mut i = 0;
+    while i < RECORDS.len() {
+        table[RECORDS[i].value] = i as u8 + 1;
+        i += 1;
+    }
+    table
+}
+
+const fn get(slot: u8) -> Option<Country> {
+    match slot {
+        0 => None,
+        _ => Country::from_index(slot as usize - 1),
+    }
+}
+
+fn get_ref(slot: u8) -> Option<&'static Country> {
+    COUNTRIES.get(usize::from(slot).checked_sub(1)?)
+}
+
+/// The country with the alpha2 code in `bytes` ignoring ASCII case
+pub(crate) const fn alpha2(bytes: &[u8]) -> Option<Country> {
+    let &[a, b] = bytes else {
+        return None;
+    };
+    let (a, b) = (letter(a), letter(b));
+    if a >= 26 || b >= 26 {
+        return None;
+    }
+    get(ALPHA2[a * 26 + b])
+}
+
+/// The country with the alpha3 code in `bytes` ignoring ASCII case
+pub(crate) const fn alpha3(bytes: &[u8]) -> Option<Country> {
+    let &[a, b, c] = bytes else {
+        return None;
+    };
+    let (a, b, c) = (letter(a), letter(b), letter(c));
+    if a >= 26 || b >= 26 || c >= 26 {
+        return None;
+    }
+    get(ALPHA3[(a * 26 + b) * 26 + c])
+}
+
+/// The country with the three digit code in `bytes`
+pub(crate) const fn code(bytes: &[u8]) -> Option<Country> {
+    let &[a, b, c] = bytes else {
+        return None;
+    };
+    let (a, b, c) = (digit(a), digit(b), digit(c));
+    if a >= 10 || b >= 10 || c >= 10 {
+        return None;
+    }
+    get(NUMERIC[(a * 10 + b) * 10 + c])
+}
+
+/// The country with the numeric `value`
+pub(crate) const fn value(value: usize) -> Option<Country> {
+    if value < NUMERIC.len() {
+        get(NUMERIC[value])
+    } else {
+        None
+    }
+}
+
+impl Country {
+    /// The number of compiled-in countries
+    pub const COUNT: usize = 250;
+
+    /// Every compiled-in country in the order of `Country::get_countries`
+    /// without copying them
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Country::COUNT, Country::all().len());
+    /// assert_eq!(Country::afghanistan(), Country::all()[0]);
+    /// ```
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &COUNTRIES
+    }
+
+    /// Every compiled-in country ordered by alpha2 code
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let first = Country::all_by_alpha2().next().expect("countries");
+    /// assert_eq!("AD", first.alpha2());
+    /// ```
+    pub fn all_by_alpha2() -> impl Iterator<Item = &'static Self> {
+        ALPHA2.iter().filter_map(|slot| get_ref(*slot))
+    }
+
+    /// Every compiled-in country ordered by alpha3 code
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let first = Country::all_by_alpha3().next().expect("countries");
+    /// assert_eq!("ABW", first.alpha3());
+    /// ```
+    pub fn all_by_alpha3() -> impl Iterator<Item = &'static Self> {
+        ALPHA3.iter().filter_map(|slot| get_ref(*slot))
+    }
+
+    /// Every compiled-in country ordered by numeric value
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let first = Country::all_by_numeric().next().expect("countries");
+    /// assert_eq!("004", first.code());
+    /// ```
+    pub fn all_by_numeric() -> impl Iterator<Item = &'static Self> {
+        NUMERIC.iter().filter_map(|slot| get_ref(*slot))
+    }
+
+    /// The position of this country in [`Country::all`]. Use it to key arrays by
+    /// country. Indices only change when countries are added to or removed from
+    /// the dataset. An installed override has the index of the country it replaces.
+    /// Countries only created at runtime, like registered user-assigned territories,
+    /// have indices from [`Country::COUNT`] up in the order they were created.
+    /// Use [`Country::checked_index`] to exclude them.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let mut orders = [0u32; Country::COUNT];
+    /// orders[Country::germany().index()] += 1;
+    /// assert_eq!(Country::germany(), Country::all()[Country::germany().index()]);
+    ///
+    /// const DE: usize = Country::germany().index();
+    /// assert_eq!(1, orders[DE]);
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> usize {
+        let index = self.handle_index();
+        if index < Self::COUNT {
+            return index;
+        }
+        // Runtime handles carry the slot of the compiled-in country they replace
+        let stride = Self::COUNT + 1;
+        let slot = (index - Self::COUNT) % stride;
+        if slot < Self::COUNT {
+            slot
+        } else {
+            Self::COUNT + (index - Self::COUNT) / stride
+        }
+    }
+
+    /// The position of this country in [`Country::all`], or `None` for countries
+    /// only created at runtime like registered user-assigned territories
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Some(Country::germany().index()), Country::germany().checked_index());
+    /// ```
+    #[must_use]
+    pub const fn checked_index(self) -> Option<usize> {
+        let index = self.index();
+        if index < Self::COUNT {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Given the alpha2 letters, return the compiled-in country or `None`.
+    /// This is case-insensitive and can be evaluated at compile time, so
+    /// constants keyed by code fail the build on typos. Runtime overrides
+    /// and registered territories are not consulted.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// const GB: Country = Country::from_alpha2_const("GB").expect("valid alpha2");
+    /// assert_eq!(Country::the_united_kingdom_of_great_britain_and_northern_ireland(), GB);
+    /// assert!(Country::from_alpha2_const("UK").is_none());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use celes::Country;
+    ///
+    /// const TYPO: Country = Country::from_alpha2_const("GX").expect("valid alpha2");
+    /// ```
+    #[must_use]
+    pub const fn from_alpha2_const(alpha2: &str) -> Option<Self> {
+        self::alpha2(alpha2.as_bytes())
+    }
+
+    /// Given the alpha3 letters, return the compiled-in country or `None`.
+    /// This is case-insensitive and can be evaluated at compile time.
+    /// Runtime overrides and registered territories are not consulted.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// static SHIPPING: [Country; 2] = [
+    ///     Country::from_alpha3_const("deu").expect("valid alpha3"),
+    ///     Country::from_alpha3_const("FRA").expect("valid alpha3"),
+    /// ];
+    /// assert_eq!(Country::germany(), SHIPPING[0]);
+    /// ```
+    #[must_use]
+    pub const fn from_alpha3_const(alpha3: &str) -> Option<Self> {
+        self::alpha3(alpha3.as_bytes())
+    }
+
+    /// Given the numeric code as an integer, return the compiled-in country or `None`.
+    /// This can be evaluated at compile time. Runtime overrides and registered
+    /// territories are not consulted.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// const AL: Country = Country::from_value_const(8).expect("valid value");
+    /// assert_eq!(Country::albania(), AL);
+    /// assert!(Country::from_value_const(2).is_none());
+    /// ```
+    #[must_use]
+    pub const fn from_value_const(value: usize) -> Option<Self> {
+        self::value(value)
+    }
+}
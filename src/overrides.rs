@@ -0,0 +1,468 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Runtime overrides layered over the compiled-in tables
+//!
+//! ISO sometimes changes names or codes before a new release is published.
+//! An override dataset in JSON or CSV with the same shape as the `Country`
+//! fields can be installed at startup. Every `Country::from_*` lookup and
+//! `FromStr` consult the installed overrides first. An override replaces the
+//! compiled-in country with the same numeric value, otherwise it adds a new entry.
+//! When an override changes a country's codes the old codes stop resolving, while
+//! the replaced country's names and aliases resolve to the override.
+//!
+//! JSON is an array of objects:
+//!
+//! ```json
+//! [{ "code": "792", "value": 792, "alpha2": "TR", "alpha3": "TUR", "long_name": "Türkiye", "aliases": ["Turkey"] }]
+//! ```
+//!
+//! CSV has a header row naming the columns and separates aliases with `|`:
+//!
+//! ```csv
+//! code,value,alpha2,alpha3,long_name,aliases
+//! 792,792,TR,TUR,Türkiye,Turkey|Turkiye
+//! ```
+//!
+//! Every record is checked before any is stored, so a rejected dataset leaves nothing behind.
+//! Accepted strings are leaked to give them the `'static` lifetime `Country` requires,
+//! so install a dataset once at startup rather than repeatedly.
+
+use crate::{AliasRegistry, Country, CountryFields, CountryTable, runtime, territory};
+use serde::Deserialize;
+use std::{
+    fmt::{self, Display, Formatter},
+    fs,
+    path::Path,
+    sync::{PoisonError, RwLock},
+};
+
+static INSTALLED: RwLock<Option<Overrides>> = RwLock::new(None);
+
+/// The errors that can occur when loading overrides
+#[derive(Debug)]
+pub enum OverrideError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The JSON could not be parsed
+    Json(serde_json::Error),
+    /// The CSV could not be parsed
+    Csv(csv::Error),
+    /// The file extension is neither `json` nor `csv`
+    UnknownFormat,
+    /// A record has an invalid field
+    InvalidRecord {
+        /// The zero-based index of the record
+        index: usize,
+        /// Which field is invalid
+        reason: &'static str,
+    },
+}
+
+impl Display for OverrideError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "unable to read overrides: {e}"),
+            Self::Json(e) => write!(f, "invalid json: {e}"),
+            Self::Csv(e) => write!(f, "invalid csv: {e}"),
+            Self::UnknownFormat => write!(f, "unknown override format"),
+            Self::InvalidRecord { index, reason } => write!(f, "record {index}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for OverrideError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Json(e) => Some(e),
+            Self::Csv(e) => Some(e),
+            Self::UnknownFormat | Self::InvalidRecord { .. } => None,
+        }
+    }
+}
+
+/// A record as it appears in JSON, and in CSV once its aliases are split
+#[derive(Deserialize)]
+struct Record {
+    code: String,
+    value: usize,
+    alpha2: String,
+    alpha3: String,
+    long_name: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CsvRecord {
+    code: String,
+    value: usize,
+    alpha2: String,
+    alpha3: String,
+    long_name: String,
+    #[serde(default)]
+    aliases: String,
+}
+
+impl From<CsvRecord> for Record {
+    fn from(r: CsvRecord) -> Self {
+        Self {
+            code: r.code,
+            value: r.value,
+            alpha2: r.alpha2,
+            alpha3: r.alpha3,
+            long_name: r.long_name,
+            aliases: r.aliases.split('|').map(str::to_string).collect(),
+        }
+    }
+}
+
+impl Record {
+    /// Check the record at `index` against its own fields and the records before it
+    fn check(&self, index: usize, earlier: &[Self]) -> Result<(), OverrideError> {
+        let invalid = |reason| OverrideError::InvalidRecord { index, reason };
+        if self.code.len() != 3 || self.code.parse::<usize>().ok() != Some(self.value) {
+            return Err(invalid("code must be the three digit value"));
+        }
+        if self.alpha2.len() != 2 || !self.alpha2.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(invalid("alpha2 must be two letters"));
+        }
+        if self.alpha3.len() != 3 || !self.alpha3.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(invalid("alpha3 must be three letters"));
+        }
+        if self.long_name.trim().is_empty() {
+            return Err(invalid("long_name must not be empty"));
+        }
+        if earlier.iter().any(|r| r.value == self.value) {
+            return Err(invalid("duplicate value"));
+        }
+        Ok(())
+    }
+
+    /// Store the checked record at `index` as a runtime country
+    fn register(self, index: usize) -> Result<Entry, OverrideError> {
+        let aliases: Vec<&'static str> = self
+            .aliases
+            .iter()
+            .map(|a| a.trim())
+            .filter(|a| !a.is_empty())
+            .map(|a| leak(a.to_string()))
+            .collect();
+        let mut normalized: Vec<String> = aliases.iter().map(|a| normalize(a)).collect();
+        normalized.sort_unstable();
+        normalized.dedup();
+        #[allow(deprecated)]
+        let country = runtime::register(CountryFields {
+            code: leak(self.code),
+            value: self.value,
+            alpha2: leak(self.alpha2.to_ascii_uppercase()),
+            alpha3: leak(self.alpha3.to_ascii_uppercase()),
+            long_name: leak(self.long_name.trim().to_string()),
+            aliases: CountryTable::new(Box::leak(aliases.into_boxed_slice())),
+        })
+        .map_err(|reason| OverrideError::InvalidRecord { index, reason })?;
+        Ok(Entry {
+            country,
+            name: normalize(&self.long_name),
+            aliases: normalized,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    country: Country,
+    /// The normalized long name
+    name: String,
+    /// The normalized aliases, sorted for binary search
+    aliases: Vec<String>,
+}
+
+/// A dataset of countries that takes precedence over the compiled-in tables
+#[derive(Clone, Debug, Default)]
+pub struct Overrides {
+    entries: Vec<Entry>,
+}
+
+impl Overrides {
+    /// Parse overrides from a JSON array of country records
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed or a record is invalid.
+    ///
+    /// ```
+    /// use celes::overrides::Overrides;
+    ///
+    /// let overrides = Overrides::from_json(r#"[
+    ///     {"code": "792", "value": 792, "alpha2": "TR", "alpha3": "TUR", "long_name": "Turkiye"}
+    /// ]"#).expect("valid overrides");
+    /// assert_eq!("Turkiye", overrides.countries().next().expect("one entry").long_name());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, OverrideError> {
+        Self::from_records(serde_json::from_str(json).map_err(OverrideError::Json)?)
+    }
+
+    /// Parse overrides from CSV with a header row. Aliases are separated by `|`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CSV is malformed or a record is invalid.
+    ///
+    /// ```
+    /// use celes::overrides::Overrides;
+    ///
+    /// let overrides = Overrides::from_csv(
+    ///     "code,value,alpha2,alpha3,long_name,aliases\n792,792,TR,TUR,Turkiye,Turkey|Türkiye\n",
+    /// ).expect("valid overrides");
+    /// assert_eq!(1, overrides.countries().count());
+    /// ```
+    pub fn from_csv(csv: &str) -> Result<Self, OverrideError> {
+        csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes())
+            .deserialize::<CsvRecord>()
+            .map(|r| r.map(Record::from).map_err(OverrideError::Csv))
+            .collect::<Result<_, _>>()
+            .and_then(Self::from_records)
+    }
+
+    /// Read overrides from a file. The format is chosen by the `json` or `csv` extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, has an unknown extension
+    /// or its contents are invalid.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, OverrideError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .ok_or(OverrideError::UnknownFormat)?;
+        let parse = if extension.eq_ignore_ascii_case("json") {
+            Self::from_json
+        } else if extension.eq_ignore_ascii_case("csv") {
+            Self::from_csv
+        } else {
+            return Err(OverrideError::UnknownFormat);
+        };
+        parse(&fs::read_to_string(path).map_err(OverrideError::Io)?)
+    }
+
+    /// The countries in this dataset
+    pub fn countries(&self) -> impl Iterator<Item = Country> + '_ {
+        self.entries.iter().map(|e| e.country)
+    }
+
+    /// Check every record before storing any so a rejected dataset leaves nothing behind
+    fn from_records(records: Vec<Record>) -> Result<Self, OverrideError> {
+        for (index, r) in records.iter().enumerate() {
+            r.check(index, &records[..index])?;
+        }
+        let entries = records
+            .into_iter()
+            .enumerate()
+            .map(|(index, r)| r.register(index))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+}
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+/// Remove spaces and underscores and lowercase the rest
+fn normalized(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars()
+        .filter(|c| *c != ' ' && *c != '_')
+        .flat_map(char::to_lowercase)
+}
+
+fn normalize(s: &str) -> String {
+    normalized(s).collect()
+}
+
+/// Stack buffer large enough for any compiled-in name or alias once normalized
+const KEY_LEN: usize = 128;
+
+/// The normalized text of a lookup, computed once before any lock is taken.
+/// Only text longer than the stack buffer allocates.
+enum Key {
+    Stack([u8; KEY_LEN], usize),
+    Heap(String),
+}
+
+impl Key {
+    fn new(s: &str) -> Self {
+        let mut buf = [0u8; KEY_LEN];
+        let mut len = 0;
+        for c in normalized(s) {
+            if len + c.len_utf8() > KEY_LEN {
+                return Self::Heap(normalize(s));
+            }
+            len += c.encode_utf8(&mut buf[len..]).len();
+        }
+        Self::Stack(buf, len)
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            // Only whole chars are encoded into the buffer
+            Self::Stack(buf, len) => core::str::from_utf8(&buf[..*len]).unwrap_or_default(),
+            Self::Heap(s) => s,
+        }
+    }
+}
+
+/// Install `overrides`, replacing any that were installed before
+pub fn install(overrides: Overrides) {
+    *INSTALLED.write().unwrap_or_else(PoisonError::into_inner) = Some(overrides);
+}
+
+/// Read overrides from a file and install them
+///
+/// # Errors
+///
+/// Returns an error if the file can't be loaded. Installed overrides are left unchanged.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<(), OverrideError> {
+    install(Overrides::from_path(path)?);
+    Ok(())
+}
+
+/// Remove installed overrides so only the compiled-in tables are used
+pub fn clear() {
+    *INSTALLED.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// The currently installed countries
+#[must_use]
+pub fn installed() -> Vec<Country> {
+    INSTALLED
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(|o| o.countries().collect())
+        .unwrap_or_default()
+}
+
+/// Which field a lookup matches against
+#[derive(Copy, Clone)]
+pub(crate) enum Field<'a> {
+    Value(usize),
+    Code(&'a str),
+    Alpha2(&'a str),
+    Alpha3(&'a str),
+    Alias(&'a str),
+    Name(&'a str),
+    Any(&'a str),
+}
+
+impl Field<'_> {
+    /// The normalized text compared against names and aliases
+    fn key(self) -> Key {
+        match self {
+            Self::Alias(s) | Self::Name(s) | Self::Any(s) => Key::new(s),
+            Self::Value(_) | Self::Code(_) | Self::Alpha2(_) | Self::Alpha3(_) => Key::new(""),
+        }
+    }
+
+    fn matches(self, key: &str, entry: &Entry) -> bool {
+        match self {
+            Self::Alias(_) => entry
+                .aliases
+                .binary_search_by(|a| a.as_str().cmp(key))
+                .is_ok(),
+            Self::Name(_) => entry.name == key,
+            Self::Any(s) => {
+                Self::Alias(s).matches(key, entry)
+                    || Self::Name(s).matches(key, entry)
+                    || self.matches_code(entry.country)
+            }
+            _ => self.matches_code(entry.country),
+        }
+    }
+
+    /// Match against the country's codes and name but not its aliases.
+    /// `key` is the normalized text of the field.
+    pub(crate) fn matches_country(self, key: &str, c: Country) -> bool {
+        match self {
+            Self::Alias(_) => false,
+            Self::Name(_) => normalized(c.long_name()).eq(key.chars()),
+            Self::Any(s) => Self::Name(s).matches_country(key, c) || self.matches_code(c),
+            _ => self.matches_code(c),
+        }
+    }
+
+    fn matches_code(self, c: Country) -> bool {
+        match self {
+            Self::Value(value) => c.value() == value,
+            Self::Code(code) => c.code() == code,
+            Self::Alpha2(alpha2) => c.alpha2().eq_ignore_ascii_case(alpha2),
+            Self::Alpha3(alpha3) => c.alpha3().eq_ignore_ascii_case(alpha3),
+            Self::Alias(_) | Self::Name(_) => false,
+            Self::Any(s) => [Self::Code(s), Self::Alpha2(s), Self::Alpha3(s)]
+                .into_iter()
+                .any(|f| f.matches_code(c)),
+        }
+    }
+
+    /// The error a lookup by this field returns when nothing matched
+    const fn error(self) -> &'static str {
+        match self {
+            Self::Value(_) => "invalid value",
+            Self::Code(_) => "invalid code",
+            Self::Alpha2(_) => "invalid alpha2",
+            Self::Alpha3(_) => "invalid alpha3",
+            Self::Alias(_) => "invalid alias",
+            Self::Name(_) | Self::Any(_) => "unknown value",
+        }
+    }
+}
+
+/// Layer the installed overrides over the result of a compiled-in lookup, then fall
+/// back to registered user-assigned territories. Lookups by any identifier also fall
+/// back to the installed alias registry.
+pub(crate) fn layer(
+    field: Field<'_>,
+    base: Result<Country, &'static str>,
+) -> Result<Country, &'static str> {
+    let key = field.key();
+    let key = key.as_str();
+    let res = layer_overrides(field, key, base).or_else(|e| territory::find(field, key).ok_or(e));
+    match field {
+        Field::Any(alias) => res.or_else(|e| AliasRegistry::get_installed(alias).ok_or(e)),
+        _ => res,
+    }
+}
+
+/// An override replaces the compiled-in country with the same value. The replaced
+/// country's old names and aliases still resolve to the override, but codes the
+/// override changed no longer resolve at all.
+fn layer_overrides(
+    field: Field<'_>,
+    key: &str,
+    base: Result<Country, &'static str>,
+) -> Result<Country, &'static str> {
+    let guard = INSTALLED.read().unwrap_or_else(PoisonError::into_inner);
+    let Some(overrides) = guard.as_ref() else {
+        return base;
+    };
+    if let Some(entry) = overrides.entries.iter().find(|e| field.matches(key, e)) {
+        return Ok(entry.country);
+    }
+    base.and_then(|country| {
+        match overrides
+            .entries
+            .iter()
+            .find(|e| e.country.value() == country.value())
+        {
+            // The override didn't match so the code that found the compiled-in country was changed
+            Some(_) if field.matches_code(country) => Err(field.error()),
+            Some(e) => Ok(e.country),
+            None => Ok(country),
+        }
+    })
+}
@@ -0,0 +1,387 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Configurable parsing for APIs that need stricter or more lenient lookups than `FromStr`
+
+use crate::{Country, LookupTable};
+
+/// Options that control which inputs a [`Parser`] accepts.
+///
+/// The defaults match `Country::from_str`: every identifier kind, case-insensitive,
+/// deprecated aliases accepted, numeric codes must be three digits and no trimming.
+///
+/// ```
+/// use celes::{Country, ParseOptions};
+///
+/// let parser = ParseOptions::none()
+///     .alpha2(true)
+///     .case_sensitive(true)
+///     .trim(true)
+///     .build();
+/// assert_eq!(Country::germany(), parser.parse(" DE ").expect("alpha2"));
+/// assert!(parser.parse("de").is_err());
+/// assert!(parser.parse("DEU").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    alpha2: bool,
+    alpha3: bool,
+    numeric: bool,
+    names: bool,
+    aliases: bool,
+    case_sensitive: bool,
+    deprecated_aliases: bool,
+    unpadded_numeric: bool,
+    trim: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Options matching `Country::from_str`
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            alpha2: true,
+            alpha3: true,
+            numeric: true,
+            names: true,
+            aliases: true,
+            case_sensitive: false,
+            deprecated_aliases: true,
+            unpadded_numeric: false,
+            trim: false,
+        }
+    }
+
+    /// Options that accept no identifier kinds. Enable the ones you want.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            alpha2: false,
+            alpha3: false,
+            numeric: false,
+            names: false,
+            aliases: false,
+            ..Self::new()
+        }
+    }
+
+    /// Accept alpha2 codes like `GB`
+    #[must_use]
+    pub const fn alpha2(mut self, accept: bool) -> Self {
+        self.alpha2 = accept;
+        self
+    }
+
+    /// Accept alpha3 codes like `GBR`
+    #[must_use]
+    pub const fn alpha3(mut self, accept: bool) -> Self {
+        self.alpha3 = accept;
+        self
+    }
+
+    /// Accept numeric codes like `826`
+    #[must_use]
+    pub const fn numeric(mut self, accept: bool) -> Self {
+        self.numeric = accept;
+        self
+    }
+
+    /// Accept official names without spaces like `TheNetherlands`
+    /// and snake case names like `the_netherlands`
+    #[must_use]
+    pub const fn names(mut self, accept: bool) -> Self {
+        self.names = accept;
+        self
+    }
+
+    /// Accept aliases like `Holland`
+    #[must_use]
+    pub const fn aliases(mut self, accept: bool) -> Self {
+        self.aliases = accept;
+        self
+    }
+
+    /// Require codes to be uppercase and names and aliases to match their
+    /// canonical spelling exactly
+    #[must_use]
+    pub const fn case_sensitive(mut self, sensitive: bool) -> Self {
+        self.case_sensitive = sensitive;
+        self
+    }
+
+    /// Accept aliases for names a country no longer uses, like `Swaziland`
+    #[must_use]
+    pub const fn deprecated_aliases(mut self, accept: bool) -> Self {
+        self.deprecated_aliases = accept;
+        self
+    }
+
+    /// Accept numeric codes without leading zeros, like `4` for `004`
+    #[must_use]
+    pub const fn unpadded_numeric(mut self, accept: bool) -> Self {
+        self.unpadded_numeric = accept;
+        self
+    }
+
+    /// Ignore leading and trailing whitespace
+    #[must_use]
+    pub const fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Create a parser using these options
+    #[must_use]
+    pub const fn build(self) -> Parser {
+        Parser { options: self }
+    }
+}
+
+/// A reusable country parser created from [`ParseOptions`]
+///
+/// ```
+/// use celes::{Country, ParseOptions, Parser};
+///
+/// static NUMERIC: Parser = ParseOptions::none().numeric(true).unpadded_numeric(true).build();
+///
+/// assert_eq!(Country::afghanistan(), NUMERIC.parse("4").expect("unpadded"));
+/// assert_eq!(Country::afghanistan(), NUMERIC.parse("004").expect("padded"));
+/// assert!(NUMERIC.parse("AF").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Parser {
+    options: ParseOptions,
+}
+
+impl Parser {
+    /// The options this parser was built with
+    #[must_use]
+    pub const fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    /// Parse `input` into a country
+    ///
+    /// # Errors
+    ///
+    /// Returns `"unknown value"` if the input isn't accepted by the options.
+    pub fn parse<A: AsRef<str>>(&self, input: A) -> Result<Country, &'static str> {
+        let o = &self.options;
+        let input = if o.trim {
+            input.as_ref().trim()
+        } else {
+            input.as_ref()
+        };
+        if input.is_empty() {
+            return Err("unknown value");
+        }
+        if input.bytes().all(|b| b.is_ascii_digit()) {
+            return self.parse_numeric(input);
+        }
+        let ascii_letters = input.bytes().all(|b| b.is_ascii_alphabetic());
+        let uppercase = !o.case_sensitive || input.bytes().all(|b| b.is_ascii_uppercase());
+        if o.alpha2
+            && ascii_letters
+            && uppercase
+            && input.len() == 2
+            && let Ok(country) = Country::from_alpha2(input)
+        {
+            return Ok(country);
+        }
+        if o.alpha3
+            && ascii_letters
+            && uppercase
+            && input.len() == 3
+            && let Ok(country) = Country::from_alpha3(input)
+        {
+            return Ok(country);
+        }
+        if (o.names || o.aliases)
+            && let Some(details) = Country::parse_text(input)
+            && match details.kind {
+                MatchKind::Name | MatchKind::SnakeCaseName => o.names,
+                MatchKind::Alias => o.aliases,
+                MatchKind::Alpha2 | MatchKind::Alpha3 | MatchKind::Numeric => false,
+            }
+            && (!o.case_sensitive || !details.case_folded)
+            && (o.deprecated_aliases || !details.country.is_outdated_name(input))
+        {
+            return Ok(details.country);
+        }
+        Err("unknown value")
+    }
+
+    fn parse_numeric(&self, input: &str) -> Result<Country, &'static str> {
+        let o = &self.options;
+        if !o.numeric {
+            return Err("unknown value");
+        }
+        match input.len() {
+            3 => Country::from_code(input),
+            1 | 2 if o.unpadded_numeric => {
+                let mut buf = [b'0'; 3];
+                buf[3 - input.len()..].copy_from_slice(input.as_bytes());
+                // SAFETY: only ASCII digits are written to the buffer
+                Country::from_code(unsafe { core::str::from_utf8_unchecked(&buf) })
+            }
+            _ => Err("unknown value"),
+        }
+    }
+}
+
+/// The kind of identifier that matched when parsing
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    /// A two letter code like `GB`
+    Alpha2,
+    /// A three letter code like `GBR`
+    Alpha3,
+    /// A three digit code like `826`
+    Numeric,
+    /// The official name without spaces like `TheNetherlands`
+    Name,
+    /// An alias like `Holland`
+    Alias,
+    /// The snake case constructor name like `the_netherlands`
+    SnakeCaseName,
+}
+
+/// The result of [`Country::parse_detailed`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseDetails {
+    /// The country that matched
+    pub country: Country,
+    /// The kind of identifier that matched
+    pub kind: MatchKind,
+    /// True if the input only matched after ignoring case
+    pub case_folded: bool,
+}
+
+impl Country {
+    /// Parse any input accepted by `Country::from_str` and report which kind of
+    /// identifier matched and whether the match needed case folding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"unknown value"` if nothing matched.
+    ///
+    /// ```
+    /// use celes::{Country, MatchKind};
+    ///
+    /// let details = Country::parse_detailed("gbr").expect("alpha3");
+    /// assert_eq!(Country::the_united_kingdom_of_great_britain_and_northern_ireland(), details.country);
+    /// assert_eq!(MatchKind::Alpha3, details.kind);
+    /// assert!(details.case_folded);
+    ///
+    /// let details = Country::parse_detailed("Holland").expect("alias");
+    /// assert_eq!(MatchKind::Alias, details.kind);
+    /// assert!(!details.case_folded);
+    /// ```
+    pub fn parse_detailed<A: AsRef<str>>(input: A) -> Result<ParseDetails, &'static str> {
+        let input = input.as_ref();
+        let details = |country: Self, kind, canonical: bool| ParseDetails {
+            country,
+            kind,
+            case_folded: !canonical,
+        };
+        let ascii_letters = input.bytes().all(|b| b.is_ascii_alphabetic());
+        if input.len() == 3 && input.bytes().all(|b| b.is_ascii_digit()) {
+            return Self::from_code(input).map(|c| details(c, MatchKind::Numeric, true));
+        }
+        if ascii_letters
+            && input.len() == 2
+            && let Ok(c) = Self::from_alpha2(input)
+        {
+            return Ok(details(c, MatchKind::Alpha2, c.alpha2() == input));
+        }
+        if ascii_letters
+            && input.len() == 3
+            && let Ok(c) = Self::from_alpha3(input)
+        {
+            return Ok(details(c, MatchKind::Alpha3, c.alpha3() == input));
+        }
+        Self::parse_text(input).ok_or("unknown value")
+    }
+
+    /// Match `input` against names and aliases, falling back to everything else
+    /// `FromStr` accepts except codes
+    fn parse_text(input: &str) -> Option<ParseDetails> {
+        let details = |country, kind, canonical: bool| ParseDetails {
+            country,
+            kind,
+            case_folded: !canonical,
+        };
+        let is_name = |c: Self| {
+            c.long_name() == input
+                || c.long_name()
+                    .chars()
+                    .filter(|ch| *ch != ' ')
+                    .eq(input.chars())
+        };
+        if input.contains('_') {
+            // The only identifiers containing underscores are snake case names
+            let canonical = !input.bytes().any(|b| b.is_ascii_uppercase());
+            return input
+                .parse::<Self>()
+                .ok()
+                .map(|c| details(c, MatchKind::SnakeCaseName, canonical));
+        }
+        if let Ok(c) = Self::from_name(input) {
+            return Some(details(c, MatchKind::Name, is_name(c)));
+        }
+        if let Ok(c) = Self::from_alias(input) {
+            let canonical = c.aliases().iter().any(|a| *a == input);
+            return Some(details(c, MatchKind::Alias, canonical));
+        }
+        // Names and aliases only `FromStr` knows, like `United States` or registered aliases
+        let c = input.parse::<Self>().ok()?;
+        let is_code = [c.alpha2(), c.alpha3(), c.code()]
+            .iter()
+            .any(|code| code.eq_ignore_ascii_case(input));
+        if is_code {
+            return None;
+        }
+        if squashed(c.long_name()).eq(squashed(input)) {
+            Some(details(c, MatchKind::Name, is_name(c)))
+        } else {
+            let canonical = c.aliases().iter().any(|a| *a == input);
+            Some(details(c, MatchKind::Alias, canonical))
+        }
+    }
+}
+
+/// Lowercase `s` without its spaces
+fn squashed(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().filter(|c| *c != ' ').flat_map(char::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+
+    // The name map is private, so this is the one place every key can be checked
+    #[test]
+    fn detailed_parse_accepts_every_from_str_key() {
+        for (key, country) in Country::from_str_table() {
+            assert_eq!(
+                *country,
+                Country::parse_detailed(key).expect(key).country,
+                "{key}"
+            );
+            assert_eq!(*country, crate::Parser::default().parse(key).expect(key));
+        }
+        for country in Country::get_countries() {
+            for code in [country.alpha2(), country.alpha3(), country.code()] {
+                assert_eq!(country, Country::parse_detailed(code).expect(code).country);
+            }
+        }
+    }
+}
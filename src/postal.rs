@@ -0,0 +1,425 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Postal code formats for each country
+//!
+//! Patterns use the same notation as the Universal Postal Union address guides:
+//!
+//! - `N` - a digit
+//! - `A` - a letter
+//! - `?` - a letter or a digit
+//! - `\` - the next character is taken literally, e.g. `\AD` for the Andorra prefix
+//! - anything else must appear exactly as written
+//!
+//! Spaces and hyphens in a pattern are separators. They are inserted during
+//! normalization, so `k1a0b1`, `K1A-0B1` and `K1A 0B1` all normalize to `K1A 0B1`.
+
+use crate::Country;
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::Deref,
+};
+use phf::{Map, phf_map};
+
+/// The maximum length in bytes of a normalized postal code
+const MAX_POSTAL_CODE_LEN: usize = 16;
+
+/// Describes how postal codes are written in a country
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PostalCodeFormat {
+    /// The accepted patterns, the first one being the canonical pattern.
+    /// Empty if the country does not use postal codes.
+    pub patterns: &'static [&'static str],
+    /// An example of a valid postal code
+    pub example: Option<&'static str>,
+}
+
+impl PostalCodeFormat {
+    /// The format for countries that do not use postal codes
+    pub const NONE: Self = Self {
+        patterns: &[],
+        example: None,
+    };
+
+    const fn new(patterns: &'static [&'static str], example: &'static str) -> Self {
+        Self {
+            patterns,
+            example: Some(example),
+        }
+    }
+
+    /// True if the country uses postal codes
+    #[must_use]
+    pub const fn is_used(&self) -> bool {
+        !self.patterns.is_empty()
+    }
+
+    /// The canonical pattern or `None` if postal codes are not used
+    #[must_use]
+    pub const fn pattern(&self) -> Option<&'static str> {
+        match self.patterns.first() {
+            Some(p) => Some(*p),
+            None => None,
+        }
+    }
+
+    /// True if `code` matches one of the patterns after normalization
+    #[must_use]
+    pub fn validate(&self, code: &str) -> bool {
+        self.normalize(code).is_ok()
+    }
+
+    /// Normalize `code` to the canonical spacing and case of the first matching pattern
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if postal codes are not used or `code` does not match any pattern.
+    pub fn normalize(&self, code: &str) -> Result<PostalCode, &'static str> {
+        if !self.is_used() {
+            return Err("postal codes are not used");
+        }
+        self.patterns
+            .iter()
+            .find_map(|p| PostalCode::from_pattern(p, code))
+            .ok_or("invalid postal code")
+    }
+}
+
+/// A normalized postal code stored inline without heap allocation
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostalCode {
+    buf: [u8; MAX_POSTAL_CODE_LEN],
+    len: usize,
+}
+
+impl PostalCode {
+    /// The postal code as a string slice
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only ASCII bytes are ever written to the buffer
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    fn push(&mut self, b: u8) -> Option<()> {
+        *self.buf.get_mut(self.len)? = b;
+        self.len += 1;
+        Some(())
+    }
+
+    fn from_pattern(pattern: &str, code: &str) -> Option<Self> {
+        let mut out = Self {
+            buf: [0u8; MAX_POSTAL_CODE_LEN],
+            len: 0,
+        };
+        let mut input = code
+            .trim()
+            .bytes()
+            .filter(|b| !is_separator(*b))
+            .map(|b| b.to_ascii_uppercase());
+        let mut pattern = pattern.bytes();
+        while let Some(p) = pattern.next() {
+            match p {
+                b' ' | b'-' => out.push(p)?,
+                b'N' => out.push(input.next().filter(u8::is_ascii_digit)?)?,
+                b'A' => out.push(input.next().filter(u8::is_ascii_alphabetic)?)?,
+                b'?' => out.push(input.next().filter(u8::is_ascii_alphanumeric)?)?,
+                b'\\' => {
+                    let lit = pattern.next()?;
+                    out.push(input.next().filter(|b| *b == lit)?)?;
+                }
+                _ => out.push(input.next().filter(|b| *b == p)?)?,
+            }
+        }
+        if input.next().is_some() {
+            return None;
+        }
+        Some(out)
+    }
+}
+
+impl Deref for PostalCode {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for PostalCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for PostalCode {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PostalCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Debug for PostalCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "PostalCode({})", self.as_str())
+    }
+}
+
+impl Display for PostalCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+const fn is_separator(b: u8) -> bool {
+    matches!(b, b' ' | b'-' | b'\t')
+}
+
+/// Postal code formats keyed by alpha2. Countries without an entry do not use postal codes.
+static POSTAL_CODE_FORMATS: Map<&'static str, PostalCodeFormat> = phf_map! {
+    "AD" => PostalCodeFormat::new(&["\\ADNNN"], "AD100"),
+    "AF" => PostalCodeFormat::new(&["NNNN"], "1001"),
+    "AI" => PostalCodeFormat::new(&["\\AI-2640"], "AI-2640"),
+    "AL" => PostalCodeFormat::new(&["NNNN"], "1001"),
+    "AM" => PostalCodeFormat::new(&["NNNN"], "0010"),
+    "AR" => PostalCodeFormat::new(&["ANNNNAAA", "NNNN"], "C1070AAM"),
+    "AS" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96799"),
+    "AT" => PostalCodeFormat::new(&["NNNN"], "1010"),
+    "AU" => PostalCodeFormat::new(&["NNNN"], "2060"),
+    "AX" => PostalCodeFormat::new(&["NNNNN"], "22150"),
+    "AZ" => PostalCodeFormat::new(&["\\AZ NNNN", "NNNN"], "AZ 1000"),
+    "BA" => PostalCodeFormat::new(&["NNNNN"], "71000"),
+    "BB" => PostalCodeFormat::new(&["BBNNNNN"], "BB23026"),
+    "BD" => PostalCodeFormat::new(&["NNNN"], "1340"),
+    "BE" => PostalCodeFormat::new(&["NNNN"], "4000"),
+    "BG" => PostalCodeFormat::new(&["NNNN"], "1000"),
+    "BH" => PostalCodeFormat::new(&["NNN", "NNNN"], "317"),
+    "BL" => PostalCodeFormat::new(&["NNNNN"], "97133"),
+    "BM" => PostalCodeFormat::new(&["AA NN", "AA AA"], "FL 07"),
+    "BN" => PostalCodeFormat::new(&["AANNNN"], "BT2328"),
+    "BR" => PostalCodeFormat::new(&["NNNNN-NNN"], "40301-110"),
+    "BT" => PostalCodeFormat::new(&["NNNNN"], "11001"),
+    "BY" => PostalCodeFormat::new(&["NNNNNN"], "223016"),
+    "CA" => PostalCodeFormat::new(&["ANA NAN"], "K1A 0B1"),
+    "CC" => PostalCodeFormat::new(&["NNNN"], "6799"),
+    "CH" => PostalCodeFormat::new(&["NNNN"], "2544"),
+    "CL" => PostalCodeFormat::new(&["NNNNNNN"], "8320000"),
+    "CN" => PostalCodeFormat::new(&["NNNNNN"], "266033"),
+    "CO" => PostalCodeFormat::new(&["NNNNNN"], "111221"),
+    "CR" => PostalCodeFormat::new(&["NNNNN"], "10101"),
+    "CU" => PostalCodeFormat::new(&["NNNNN"], "10700"),
+    "CV" => PostalCodeFormat::new(&["NNNN"], "7600"),
+    "CX" => PostalCodeFormat::new(&["NNNN"], "6798"),
+    "CY" => PostalCodeFormat::new(&["NNNN"], "2008"),
+    "CZ" => PostalCodeFormat::new(&["NNN NN"], "100 00"),
+    "DE" => PostalCodeFormat::new(&["NNNNN"], "26133"),
+    "DK" => PostalCodeFormat::new(&["NNNN"], "8660"),
+    "DO" => PostalCodeFormat::new(&["NNNNN"], "11903"),
+    "DZ" => PostalCodeFormat::new(&["NNNNN"], "16000"),
+    "EC" => PostalCodeFormat::new(&["NNNNNN"], "090105"),
+    "EE" => PostalCodeFormat::new(&["NNNNN"], "69501"),
+    "EG" => PostalCodeFormat::new(&["NNNNN"], "12411"),
+    "ES" => PostalCodeFormat::new(&["NNNNN"], "28039"),
+    "ET" => PostalCodeFormat::new(&["NNNN"], "1000"),
+    "FI" => PostalCodeFormat::new(&["NNNNN"], "00550"),
+    "FK" => PostalCodeFormat::new(&["FIQQ 1ZZ"], "FIQQ 1ZZ"),
+    "FM" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96941"),
+    "FO" => PostalCodeFormat::new(&["NNN"], "100"),
+    "FR" => PostalCodeFormat::new(&["NNNNN"], "33380"),
+    "GB" => PostalCodeFormat::new(&["AN NAA", "ANN NAA", "AAN NAA", "AANN NAA", "ANA NAA", "AANA NAA"], "SW1A 1AA"),
+    "GE" => PostalCodeFormat::new(&["NNNN"], "0101"),
+    "GF" => PostalCodeFormat::new(&["NNNNN"], "97300"),
+    "GG" => PostalCodeFormat::new(&["GYN NAA", "GYNN NAA"], "GY1 1AA"),
+    "GI" => PostalCodeFormat::new(&["GX11 1\\A\\A"], "GX11 1AA"),
+    "GL" => PostalCodeFormat::new(&["NNNN"], "3900"),
+    "GN" => PostalCodeFormat::new(&["NNN"], "001"),
+    "GP" => PostalCodeFormat::new(&["NNNNN"], "97100"),
+    "GR" => PostalCodeFormat::new(&["NNN NN"], "151 24"),
+    "GS" => PostalCodeFormat::new(&["SIQQ 1ZZ"], "SIQQ 1ZZ"),
+    "GT" => PostalCodeFormat::new(&["NNNNN"], "09001"),
+    "GU" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96910"),
+    "GW" => PostalCodeFormat::new(&["NNNN"], "1000"),
+    "HM" => PostalCodeFormat::new(&["NNNN"], "7050"),
+    "HN" => PostalCodeFormat::new(&["NNNNN"], "11101"),
+    "HR" => PostalCodeFormat::new(&["NNNNN"], "10000"),
+    "HT" => PostalCodeFormat::new(&["NNNN"], "6120"),
+    "HU" => PostalCodeFormat::new(&["NNNN"], "1037"),
+    "ID" => PostalCodeFormat::new(&["NNNNN"], "40115"),
+    "IE" => PostalCodeFormat::new(&["ANN ????", "D6W ????"], "A65 F4E2"),
+    "IL" => PostalCodeFormat::new(&["NNNNNNN"], "9614303"),
+    "IM" => PostalCodeFormat::new(&["IMN NAA", "IMNN NAA"], "IM2 1AA"),
+    "IN" => PostalCodeFormat::new(&["NNNNNN", "NNN NNN"], "110034"),
+    "IO" => PostalCodeFormat::new(&["BB\\ND 1ZZ"], "BBND 1ZZ"),
+    "IQ" => PostalCodeFormat::new(&["NNNNN"], "31001"),
+    "IR" => PostalCodeFormat::new(&["NNNNN-NNNNN"], "11936-12345"),
+    "IS" => PostalCodeFormat::new(&["NNN"], "320"),
+    "IT" => PostalCodeFormat::new(&["NNNNN"], "00144"),
+    "JE" => PostalCodeFormat::new(&["JEN NAA", "JENN NAA"], "JE2 2BT"),
+    "JO" => PostalCodeFormat::new(&["NNNNN"], "11937"),
+    "JP" => PostalCodeFormat::new(&["NNN-NNNN"], "154-0023"),
+    "KE" => PostalCodeFormat::new(&["NNNNN"], "20100"),
+    "KG" => PostalCodeFormat::new(&["NNNNNN"], "720001"),
+    "KH" => PostalCodeFormat::new(&["NNNNN", "NNNNNN"], "12000"),
+    "KR" => PostalCodeFormat::new(&["NNNNN"], "03051"),
+    "KW" => PostalCodeFormat::new(&["NNNNN"], "54541"),
+    "KY" => PostalCodeFormat::new(&["KYN-NNNN"], "KY1-1100"),
+    "KZ" => PostalCodeFormat::new(&["NNNNNN", "ANNANAN"], "040900"),
+    "LA" => PostalCodeFormat::new(&["NNNNN"], "01160"),
+    "LB" => PostalCodeFormat::new(&["NNNN NNNN", "NNNN"], "2038 3054"),
+    "LC" => PostalCodeFormat::new(&["LCNN NNN"], "LC05 201"),
+    "LI" => PostalCodeFormat::new(&["NNNN"], "9496"),
+    "LK" => PostalCodeFormat::new(&["NNNNN"], "20000"),
+    "LR" => PostalCodeFormat::new(&["NNNN"], "1000"),
+    "LS" => PostalCodeFormat::new(&["NNN"], "100"),
+    "LT" => PostalCodeFormat::new(&["LT-NNNNN", "NNNNN"], "LT-04340"),
+    "LU" => PostalCodeFormat::new(&["NNNN", "L-NNNN"], "4750"),
+    "LV" => PostalCodeFormat::new(&["LV-NNNN"], "LV-1073"),
+    "MA" => PostalCodeFormat::new(&["NNNNN"], "53000"),
+    "MC" => PostalCodeFormat::new(&["980NN"], "98000"),
+    "MD" => PostalCodeFormat::new(&["MD-NNNN", "NNNN"], "MD-2012"),
+    "ME" => PostalCodeFormat::new(&["NNNNN"], "81257"),
+    "MF" => PostalCodeFormat::new(&["NNNNN"], "97150"),
+    "MG" => PostalCodeFormat::new(&["NNN"], "501"),
+    "MH" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96960"),
+    "MK" => PostalCodeFormat::new(&["NNNN"], "1314"),
+    "MM" => PostalCodeFormat::new(&["NNNNN"], "11181"),
+    "MN" => PostalCodeFormat::new(&["NNNNN"], "65030"),
+    "MP" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96950"),
+    "MQ" => PostalCodeFormat::new(&["NNNNN"], "97220"),
+    "MS" => PostalCodeFormat::new(&["MSR NNNN"], "MSR 1250"),
+    "MT" => PostalCodeFormat::new(&["AAA NNNN"], "VLT 1117"),
+    "MU" => PostalCodeFormat::new(&["NNNNN"], "42602"),
+    "MV" => PostalCodeFormat::new(&["NNNNN"], "20026"),
+    "MX" => PostalCodeFormat::new(&["NNNNN"], "02860"),
+    "MY" => PostalCodeFormat::new(&["NNNNN"], "43000"),
+    "MZ" => PostalCodeFormat::new(&["NNNN"], "1102"),
+    "NA" => PostalCodeFormat::new(&["NNNNN"], "10001"),
+    "NC" => PostalCodeFormat::new(&["988NN"], "98814"),
+    "NE" => PostalCodeFormat::new(&["NNNN"], "8001"),
+    "NF" => PostalCodeFormat::new(&["NNNN"], "2899"),
+    "NG" => PostalCodeFormat::new(&["NNNNNN"], "100001"),
+    "NI" => PostalCodeFormat::new(&["NNNNN"], "52000"),
+    "NL" => PostalCodeFormat::new(&["NNNN AA"], "1234 AB"),
+    "NO" => PostalCodeFormat::new(&["NNNN"], "0025"),
+    "NP" => PostalCodeFormat::new(&["NNNNN"], "44601"),
+    "NZ" => PostalCodeFormat::new(&["NNNN"], "6001"),
+    "OM" => PostalCodeFormat::new(&["NNN"], "133"),
+    "PA" => PostalCodeFormat::new(&["NNNN"], "0801"),
+    "PE" => PostalCodeFormat::new(&["NNNNN"], "15001"),
+    "PF" => PostalCodeFormat::new(&["NNNNN"], "98709"),
+    "PG" => PostalCodeFormat::new(&["NNN"], "111"),
+    "PH" => PostalCodeFormat::new(&["NNNN"], "1008"),
+    "PK" => PostalCodeFormat::new(&["NNNNN"], "44000"),
+    "PL" => PostalCodeFormat::new(&["NN-NNN"], "00-950"),
+    "PM" => PostalCodeFormat::new(&["NNNNN"], "97500"),
+    "PN" => PostalCodeFormat::new(&["PCR\\N 1ZZ"], "PCRN 1ZZ"),
+    "PR" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "00930"),
+    "PT" => PostalCodeFormat::new(&["NNNN-NNN"], "2725-079"),
+    "PW" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96940"),
+    "PY" => PostalCodeFormat::new(&["NNNN"], "1536"),
+    "RE" => PostalCodeFormat::new(&["NNNNN"], "97400"),
+    "RO" => PostalCodeFormat::new(&["NNNNNN"], "060274"),
+    "RS" => PostalCodeFormat::new(&["NNNNN"], "11000"),
+    "RU" => PostalCodeFormat::new(&["NNNNNN"], "125075"),
+    "SA" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "11564"),
+    "SD" => PostalCodeFormat::new(&["NNNNN"], "11042"),
+    "SE" => PostalCodeFormat::new(&["NNN NN"], "114 55"),
+    "SG" => PostalCodeFormat::new(&["NNNNNN"], "546080"),
+    "SH" => PostalCodeFormat::new(&["STHL 1ZZ", "\\ASC\\N 1ZZ", "TDCU 1ZZ"], "STHL 1ZZ"),
+    "SI" => PostalCodeFormat::new(&["NNNN", "SI-NNNN"], "4000"),
+    "SJ" => PostalCodeFormat::new(&["NNNN"], "9170"),
+    "SK" => PostalCodeFormat::new(&["NNN NN"], "010 01"),
+    "SM" => PostalCodeFormat::new(&["4789N"], "47890"),
+    "SN" => PostalCodeFormat::new(&["NNNNN"], "12500"),
+    "SO" => PostalCodeFormat::new(&["AA NNNNN"], "JH 09010"),
+    "SV" => PostalCodeFormat::new(&["NNNN"], "1101"),
+    "SZ" => PostalCodeFormat::new(&["ANNN"], "H100"),
+    "TC" => PostalCodeFormat::new(&["TKC\\A 1ZZ"], "TKCA 1ZZ"),
+    "TH" => PostalCodeFormat::new(&["NNNNN"], "10150"),
+    "TJ" => PostalCodeFormat::new(&["NNNNNN"], "735450"),
+    "TM" => PostalCodeFormat::new(&["NNNNNN"], "744000"),
+    "TN" => PostalCodeFormat::new(&["NNNN"], "1002"),
+    "TR" => PostalCodeFormat::new(&["NNNNN"], "01960"),
+    "TT" => PostalCodeFormat::new(&["NNNNNN"], "120110"),
+    "TW" => PostalCodeFormat::new(&["NNN", "NNNNN", "NNNNNN"], "104"),
+    "TZ" => PostalCodeFormat::new(&["NNNNN", "NNNN"], "31000"),
+    "UA" => PostalCodeFormat::new(&["NNNNN"], "15432"),
+    "UM" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "96898"),
+    "US" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "95014"),
+    "UY" => PostalCodeFormat::new(&["NNNNN"], "11600"),
+    "UZ" => PostalCodeFormat::new(&["NNNNNN"], "702100"),
+    "VA" => PostalCodeFormat::new(&["00120"], "00120"),
+    "VC" => PostalCodeFormat::new(&["VCNNNN"], "VC0100"),
+    "VE" => PostalCodeFormat::new(&["NNNN", "NNNN-A"], "1010"),
+    "VG" => PostalCodeFormat::new(&["VGNNNN"], "VG1110"),
+    "VI" => PostalCodeFormat::new(&["NNNNN", "NNNNN-NNNN"], "00802"),
+    "VN" => PostalCodeFormat::new(&["NNNNN", "NNNNNN"], "70010"),
+    "WF" => PostalCodeFormat::new(&["986NN"], "98600"),
+    "XK" => PostalCodeFormat::new(&["NNNNN"], "10000"),
+    "YT" => PostalCodeFormat::new(&["NNNNN"], "97600"),
+    "ZA" => PostalCodeFormat::new(&["NNNN"], "0083"),
+    "ZM" => PostalCodeFormat::new(&["NNNNN"], "50100"),
+};
+
+impl Country {
+    /// The postal code format used by this country
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let format = Country::canada().postal_code_format();
+    /// assert!(format.is_used());
+    /// assert_eq!(Some("ANA NAN"), format.pattern());
+    /// assert_eq!(Some("K1A 0B1"), format.example);
+    ///
+    /// assert!(!Country::the_united_arab_emirates().postal_code_format().is_used());
+    /// ```
+    #[must_use]
+    pub fn postal_code_format(&self) -> PostalCodeFormat {
+        POSTAL_CODE_FORMATS
+            .get(self.alpha2())
+            .copied()
+            .unwrap_or(PostalCodeFormat::NONE)
+    }
+
+    /// True if `code` is a valid postal code for this country, ignoring case and spacing
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let canada = Country::canada();
+    /// assert!(canada.validate_postal_code("K1A 0B1"));
+    /// assert!(canada.validate_postal_code("k1a0b1"));
+    /// assert!(!canada.validate_postal_code("12345"));
+    /// ```
+    #[must_use]
+    pub fn validate_postal_code(&self, code: &str) -> bool {
+        self.postal_code_format().validate(code)
+    }
+
+    /// Normalize `code` to this country's canonical case and spacing
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this country does not use postal codes or `code` is invalid.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let gb = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
+    /// assert_eq!("SW1A 1AA", gb.normalize_postal_code("sw1a1aa").unwrap().as_str());
+    /// assert_eq!("EC1A 1BB", gb.normalize_postal_code(" ec1a-1bb ").unwrap().as_str());
+    ///
+    /// let us = Country::the_united_states_of_america();
+    /// assert_eq!("95014-2083", us.normalize_postal_code("95014 2083").unwrap().as_str());
+    /// assert!(us.normalize_postal_code("9501").is_err());
+    /// ```
+    pub fn normalize_postal_code(&self, code: &str) -> Result<PostalCode, &'static str> {
+        self.postal_code_format().normalize(code)
+    }
+}
@@ -0,0 +1,189 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Custom aliases registered at runtime
+
+use crate::Country;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    sync::{PoisonError, RwLock},
+};
+
+static INSTALLED: RwLock<Option<AliasRegistry>> = RwLock::new(None);
+
+/// The reasons an alias can't be registered
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AliasError {
+    /// The alias is empty or only whitespace
+    Empty,
+    /// The alias is already a built-in identifier. Contains the alpha2 code of the
+    /// country it identifies.
+    ConflictsWithBuiltin(&'static str),
+    /// The alias is already registered to a different country. Contains the alpha2
+    /// code of that country.
+    AlreadyRegistered(&'static str),
+}
+
+impl Display for AliasError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "alias is empty"),
+            Self::ConflictsWithBuiltin(alpha2) => {
+                write!(f, "alias is a built-in identifier for {alpha2}")
+            }
+            Self::AlreadyRegistered(alpha2) => write!(f, "alias is already registered to {alpha2}"),
+        }
+    }
+}
+
+impl std::error::Error for AliasError {}
+
+/// Custom aliases for countries such as internal labels. Matching is case-insensitive
+/// and aliases are stored lowercase.
+///
+/// The registry serializes as a map from alias to alpha2 code.
+///
+/// ```
+/// use celes::{AliasRegistry, AliasError, Country};
+///
+/// let mut registry = AliasRegistry::new();
+/// registry.register("Blighty", Country::the_united_kingdom_of_great_britain_and_northern_ireland()).expect("new alias");
+/// registry.register("KSA", Country::saudi_arabia()).expect("new alias");
+/// assert_eq!(Some(Country::saudi_arabia()), registry.get("ksa"));
+/// assert_eq!(
+///     Err(AliasError::ConflictsWithBuiltin("NL")),
+///     registry.register("Holland", Country::the_netherlands()),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "BTreeMap<String, Country>",
+    into = "BTreeMap<String, Country>"
+)]
+pub struct AliasRegistry {
+    aliases: BTreeMap<String, Country>,
+}
+
+impl AliasRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `alias` to `country`. Registering the same alias for the same country again
+    /// has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the alias is empty, is a built-in identifier
+    /// or is already registered to another country.
+    pub fn register<A: AsRef<str>>(
+        &mut self,
+        alias: A,
+        country: Country,
+    ) -> Result<(), AliasError> {
+        let key = alias.as_ref().trim().to_lowercase();
+        if key.is_empty() {
+            return Err(AliasError::Empty);
+        }
+        let stripped: String = key.chars().filter(|c| *c != ' ' && *c != '_').collect();
+        if let Some(builtin) =
+            Country::from_str_builtin(&key).or_else(|| Country::from_str_builtin(&stripped))
+        {
+            return Err(AliasError::ConflictsWithBuiltin(builtin.alpha2()));
+        }
+        match self.aliases.get(&key) {
+            Some(existing) if *existing != country => {
+                Err(AliasError::AlreadyRegistered(existing.alpha2()))
+            }
+            _ => {
+                self.aliases.insert(key, country);
+                Ok(())
+            }
+        }
+    }
+
+    /// Remove `alias` returning the country it mapped to
+    pub fn remove<A: AsRef<str>>(&mut self, alias: A) -> Option<Country> {
+        self.aliases.remove(&alias.as_ref().trim().to_lowercase())
+    }
+
+    /// The country registered for `alias`. This is case-insensitive.
+    #[must_use]
+    pub fn get<A: AsRef<str>>(&self, alias: A) -> Option<Country> {
+        self.aliases
+            .get(&alias.as_ref().trim().to_lowercase())
+            .copied()
+    }
+
+    /// The number of registered aliases
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /// True if no aliases are registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// The registered aliases and their countries ordered by alias
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Country)> {
+        self.aliases.iter().map(|(a, c)| (a.as_str(), *c))
+    }
+
+    /// Install this registry so `Country::from_str` consults it after the
+    /// built-in identifiers, replacing any registry installed before
+    ///
+    /// ```
+    /// use celes::{AliasRegistry, Country};
+    /// use core::str::FromStr;
+    ///
+    /// let mut registry = AliasRegistry::new();
+    /// registry.register("UAE-Dubai", Country::the_united_arab_emirates()).expect("new alias");
+    /// registry.install();
+    /// assert_eq!(Country::the_united_arab_emirates(), Country::from_str("uae-dubai").expect("registered"));
+    /// AliasRegistry::uninstall();
+    /// assert!(Country::from_str("uae-dubai").is_err());
+    /// ```
+    pub fn install(self) {
+        *INSTALLED.write().unwrap_or_else(PoisonError::into_inner) = Some(self);
+    }
+
+    /// Remove the installed registry
+    pub fn uninstall() {
+        *INSTALLED.write().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Look up `alias` in the installed registry
+    pub(crate) fn get_installed(alias: &str) -> Option<Country> {
+        INSTALLED
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .and_then(|r| r.get(alias))
+    }
+}
+
+impl TryFrom<BTreeMap<String, Country>> for AliasRegistry {
+    type Error = AliasError;
+
+    fn try_from(aliases: BTreeMap<String, Country>) -> Result<Self, Self::Error> {
+        let mut registry = Self::new();
+        for (alias, country) in aliases {
+            registry.register(alias, country)?;
+        }
+        Ok(registry)
+    }
+}
+
+impl From<AliasRegistry> for BTreeMap<String, Country> {
+    fn from(registry: AliasRegistry) -> Self {
+        registry.aliases
+    }
+}
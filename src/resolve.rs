@@ -0,0 +1,185 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Resolution of inputs that may refer to more than one country
+
+use crate::Country;
+use core::str::FromStr;
+use phf::{Map, phf_map};
+
+/// How confident a resolution is in a candidate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    /// The input could refer to this country but usually doesn't
+    Possible,
+    /// The input usually refers to this country
+    Likely,
+    /// The input is an identifier for this country alone
+    Exact,
+}
+
+/// A country an input may refer to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Candidate {
+    /// The country
+    pub country: Country,
+    /// How confident the resolution is
+    pub confidence: Confidence,
+}
+
+impl Candidate {
+    const fn likely(country: Country) -> Self {
+        Self {
+            country,
+            confidence: Confidence::Likely,
+        }
+    }
+
+    const fn possible(country: Country) -> Self {
+        Self {
+            country,
+            confidence: Confidence::Possible,
+        }
+    }
+}
+
+/// Every country an input may refer to, ordered from most to least confident
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Resolution {
+    exact: Option<Candidate>,
+    ambiguous: &'static [Candidate],
+}
+
+impl Resolution {
+    /// True if the input may refer to more than one country and the user
+    /// should be asked which one they meant
+    #[must_use]
+    pub const fn is_ambiguous(&self) -> bool {
+        self.ambiguous.len() > 1
+    }
+
+    /// True if the input didn't match any country
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.exact.is_none() && self.ambiguous.is_empty()
+    }
+
+    /// The candidates ordered from most to least confident
+    pub fn candidates(&self) -> impl Iterator<Item = Candidate> + '_ {
+        self.exact.into_iter().chain(self.ambiguous.iter().copied())
+    }
+
+    /// The country if the input matched exactly one
+    #[must_use]
+    pub fn unique(&self) -> Option<Country> {
+        self.exact.map(|c| c.country)
+    }
+}
+
+/// Inputs that refer to several countries keyed by their lowercase letters
+static AMBIGUOUS: Map<&'static str, &'static [Candidate]> = phf_map! {
+    "congo" => &[
+        Candidate::likely(Country::the_congo()),
+        Candidate::likely(Country::the_democratic_republic_of_the_congo()),
+    ],
+    "korea" => &[
+        Candidate::likely(Country::the_republic_of_korea()),
+        Candidate::possible(Country::the_democratic_peoples_republic_of_korea()),
+    ],
+    "guinea" => &[
+        Candidate::likely(Country::guinea()),
+        Candidate::possible(Country::guinea_bissau()),
+        Candidate::possible(Country::equatorial_guinea()),
+        Candidate::possible(Country::papua_new_guinea()),
+    ],
+    "samoa" => &[
+        Candidate::likely(Country::samoa()),
+        Candidate::possible(Country::american_samoa()),
+    ],
+    "virginislands" => &[
+        Candidate::likely(Country::british_virgin_islands()),
+        Candidate::likely(Country::us_virgin_islands()),
+    ],
+    "sudan" => &[
+        Candidate::likely(Country::the_sudan()),
+        Candidate::possible(Country::south_sudan()),
+    ],
+    "stmartin" => &[
+        Candidate::likely(Country::french_part_saint_martin()),
+        Candidate::possible(Country::dutch_part_sint_maarten()),
+    ],
+    "saintmartin" => &[
+        Candidate::likely(Country::french_part_saint_martin()),
+        Candidate::possible(Country::dutch_part_sint_maarten()),
+    ],
+    "stmaarten" => &[
+        Candidate::likely(Country::dutch_part_sint_maarten()),
+        Candidate::possible(Country::french_part_saint_martin()),
+    ],
+    "sintmaarten" => &[
+        Candidate::likely(Country::dutch_part_sint_maarten()),
+        Candidate::possible(Country::french_part_saint_martin()),
+    ],
+    "saintmaarten" => &[
+        Candidate::likely(Country::dutch_part_sint_maarten()),
+        Candidate::possible(Country::french_part_saint_martin()),
+    ],
+};
+
+impl Country {
+    /// Resolve an input that may refer to more than one country, such as "Congo",
+    /// "Korea" or "Virgin Islands". Spaces, underscores, hyphens and dots are ignored
+    /// when checking for ambiguity. Anything else accepted by `Country::from_str`
+    /// resolves to a single exact candidate.
+    ///
+    /// ```
+    /// use celes::{Confidence, Country};
+    ///
+    /// let resolution = Country::resolve("Korea");
+    /// assert!(resolution.is_ambiguous());
+    /// let best = resolution.candidates().next().expect("candidates");
+    /// assert_eq!(Country::the_republic_of_korea(), best.country);
+    /// assert_eq!(Confidence::Likely, best.confidence);
+    ///
+    /// let resolution = Country::resolve("KR");
+    /// assert!(!resolution.is_ambiguous());
+    /// assert_eq!(Some(Country::the_republic_of_korea()), resolution.unique());
+    /// ```
+    #[must_use]
+    pub fn resolve<A: AsRef<str>>(input: A) -> Resolution {
+        let input = input.as_ref();
+        let mut buf = [0u8; 64];
+        let mut len = 0;
+        for b in input.trim().bytes() {
+            if matches!(b, b' ' | b'_' | b'-' | b'.') {
+                continue;
+            }
+            if len == buf.len() || !b.is_ascii() {
+                len = 0;
+                break;
+            }
+            buf[len] = b.to_ascii_lowercase();
+            len += 1;
+        }
+        // SAFETY: only ASCII bytes are written to the buffer
+        let key = unsafe { core::str::from_utf8_unchecked(&buf[..len]) };
+        if let Some(ambiguous) = AMBIGUOUS.get(key) {
+            return Resolution {
+                exact: None,
+                ambiguous,
+            };
+        }
+        let exact = Self::from_str(input)
+            .or_else(|_| Self::from_str(key))
+            .ok()
+            .map(|country| Candidate {
+                country,
+                confidence: Confidence::Exact,
+            });
+        Resolution {
+            exact,
+            ambiguous: &[],
+        }
+    }
+}
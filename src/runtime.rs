@@ -0,0 +1,55 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Records for countries created at runtime
+//!
+//! A `Country` is an index into the compiled-in records followed by these.
+//! User-assigned territories and installed overrides are stored here once and
+//! never removed, so handles to them stay valid. Storing identical fields again
+//! returns the existing handle.
+//!
+//! A runtime record's index is `COUNT + generation * (COUNT + 1) + slot`, where
+//! `slot` is the position of the compiled-in country with the same value, or `COUNT`
+//! if there is none, and `generation` counts the records stored for that slot before.
+//! `Country::index` reads the slot back from the handle alone.
+
+use crate::{Country, CountryFields, index};
+use std::{
+    collections::BTreeMap,
+    sync::{PoisonError, RwLock},
+};
+
+const FULL: &str = "too many countries created at runtime";
+
+/// Runtime records keyed by their index
+static RECORDS: RwLock<BTreeMap<usize, &'static CountryFields>> = RwLock::new(BTreeMap::new());
+
+/// Store `fields` and return the handle for them
+pub(crate) fn register(fields: CountryFields) -> Result<Country, &'static str> {
+    let mut records = RECORDS.write().unwrap_or_else(PoisonError::into_inner);
+    if let Some((index, _)) = records.iter().find(|(_, r)| ***r == fields) {
+        return Country::from_index(*index).ok_or(FULL);
+    }
+    #[allow(deprecated)]
+    let slot = index::value(fields.value).map_or(Country::COUNT, Country::index);
+    let stride = Country::COUNT + 1;
+    let generation = records
+        .keys()
+        .filter(|i| (*i - Country::COUNT) % stride == slot)
+        .count();
+    let index = Country::COUNT + generation * stride + slot;
+    let country = Country::from_index(index).ok_or(FULL)?;
+    records.insert(index, Box::leak(Box::new(fields)));
+    Ok(country)
+}
+
+/// The runtime record at `index`
+pub(crate) fn get(index: usize) -> &'static CountryFields {
+    RECORDS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&index)
+        .copied()
+        .expect("handles are only created for stored records")
+}
@@ -0,0 +1,130 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Prefix search for typeahead country pickers
+
+use crate::{Country, LookupTable, index::COUNTRIES};
+
+/// Why a country matched a search prefix, from best to worst
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchRank {
+    /// The prefix is exactly the alpha2, alpha3 or numeric code
+    Code,
+    /// The official name starts with the prefix, ignoring a leading "The"
+    NameStart,
+    /// A later word in the official name starts with the prefix
+    WordStart,
+    /// An alias starts with the prefix, ignoring spaces
+    Alias,
+}
+
+const RANKS: [SearchRank; 4] = [
+    SearchRank::Code,
+    SearchRank::NameStart,
+    SearchRank::WordStart,
+    SearchRank::Alias,
+];
+
+/// A country found by [`Country::search_prefix`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SearchMatch {
+    /// The country
+    pub country: Country,
+    /// Why it matched
+    pub rank: SearchRank,
+    /// The code, name or alias that matched
+    pub matched: &'static str,
+}
+
+impl Country {
+    /// Find countries whose codes, official name or aliases start with `prefix`
+    /// for typeahead. Matching ignores ASCII case and surrounding whitespace.
+    ///
+    /// At most `limit` matches are returned, ordered by [`SearchRank`] and then
+    /// alphabetically by snake case name. Each country appears once with its best rank.
+    /// The search runs over the compiled-in dataset and never allocates.
+    ///
+    /// ```
+    /// use celes::{Country, SearchRank};
+    ///
+    /// let matches: Vec<_> = Country::search_prefix("uni", 10).collect();
+    /// assert_eq!(Country::the_united_arab_emirates(), matches[0].country);
+    /// assert_eq!(SearchRank::NameStart, matches[0].rank);
+    /// assert_eq!("The United Arab Emirates", matches[0].matched);
+    ///
+    /// let best = Country::search_prefix("de", 1).next().expect("a match");
+    /// assert_eq!(Country::germany(), best.country);
+    /// assert_eq!(SearchRank::Code, best.rank);
+    /// ```
+    pub fn search_prefix(prefix: &str, limit: usize) -> impl Iterator<Item = SearchMatch> + '_ {
+        let prefix = prefix.trim();
+        RANKS
+            .into_iter()
+            .filter(move |_| !prefix.is_empty())
+            .flat_map(move |rank| {
+                COUNTRIES.iter().filter_map(move |country| {
+                    best_match(*country, prefix).filter(|m| m.rank == rank)
+                })
+            })
+            .take(limit)
+    }
+}
+
+fn best_match(country: Country, prefix: &str) -> Option<SearchMatch> {
+    let found = |rank, matched| {
+        Some(SearchMatch {
+            country,
+            rank,
+            matched,
+        })
+    };
+    if let Some(code) = [country.alpha2(), country.alpha3(), country.code()]
+        .into_iter()
+        .find(|c| c.eq_ignore_ascii_case(prefix))
+    {
+        return found(SearchRank::Code, code);
+    }
+    let name = country.long_name();
+    let unprefixed = strip_article(name);
+    if starts_with(unprefixed, prefix) || starts_with(name, prefix) {
+        return found(SearchRank::NameStart, name);
+    }
+    if name
+        .match_indices(' ')
+        .any(|(i, _)| starts_with(&name[i + 1..], prefix))
+    {
+        return found(SearchRank::WordStart, name);
+    }
+    if let Some(alias) = country
+        .aliases()
+        .iter()
+        .find(|a| alias_starts_with(a, prefix))
+    {
+        return found(SearchRank::Alias, alias);
+    }
+    None
+}
+
+fn strip_article(name: &str) -> &str {
+    match name.get(..4) {
+        Some(the) if the.eq_ignore_ascii_case("the ") => &name[4..],
+        _ => name,
+    }
+}
+
+fn starts_with(haystack: &str, prefix: &str) -> bool {
+    haystack
+        .as_bytes()
+        .get(..prefix.len())
+        .is_some_and(|h| h.eq_ignore_ascii_case(prefix.as_bytes()))
+}
+
+/// Aliases are stored without spaces so spaces in the prefix are skipped
+fn alias_starts_with(alias: &str, prefix: &str) -> bool {
+    let mut alias = alias.bytes();
+    prefix
+        .bytes()
+        .filter(|b| *b != b' ')
+        .all(|p| alias.next().is_some_and(|a| a.eq_ignore_ascii_case(&p)))
+}
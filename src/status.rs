@@ -0,0 +1,295 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! The assignment status of alpha2 and alpha3 codes as maintained by the
+//! ISO 3166 Maintenance Agency
+
+use crate::Country;
+#[cfg(feature = "std")]
+use crate::{CountryFields, CountryTable, runtime};
+
+/// The assignment status of an alpha2 or alpha3 code
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CodeStatus {
+    /// The code is assigned to a country by ISO 3166-1
+    OfficiallyAssigned,
+    /// The code is in a range left for users to assign themselves.
+    /// Kosovo's `XK` and `XKX` are in this range.
+    UserAssigned,
+    /// The code is reserved at the request of a national standards body or
+    /// international organization. Contains what the code refers to.
+    ExceptionallyReserved(&'static str),
+    /// The code was deleted from ISO 3166-1 and is reserved for a transitional period.
+    /// Contains what the code used to refer to.
+    TransitionallyReserved(&'static str),
+    /// The code is used in another coding system, such as vehicle registration codes,
+    /// and will not be assigned without consultation. Contains what the code refers to.
+    IndeterminatelyReserved(&'static str),
+    /// The code is not assigned or reserved, or is not a two or three letter code
+    Unassigned,
+}
+
+/// The first numeric code of the user-assigned range 900 to 999
+const USER_ASSIGNED_VALUES: usize = 900;
+
+impl CodeStatus {
+    /// Classify a two or three letter code. This is case-insensitive.
+    ///
+    /// ```
+    /// use celes::CodeStatus;
+    ///
+    /// assert_eq!(CodeStatus::OfficiallyAssigned, CodeStatus::of("GB"));
+    /// assert_eq!(CodeStatus::OfficiallyAssigned, CodeStatus::of("deu"));
+    /// assert_eq!(CodeStatus::ExceptionallyReserved("United Kingdom"), CodeStatus::of("UK"));
+    /// assert_eq!(CodeStatus::TransitionallyReserved("Netherlands Antilles"), CodeStatus::of("AN"));
+    /// assert_eq!(CodeStatus::UserAssigned, CodeStatus::of("XK"));
+    /// assert_eq!(CodeStatus::UserAssigned, CodeStatus::of("QMA"));
+    /// assert_eq!(CodeStatus::Unassigned, CodeStatus::of("JJ"));
+    /// ```
+    #[must_use]
+    pub fn of(code: &str) -> Self {
+        let mut buf = [0u8; 3];
+        let len = code.len();
+        if !(2..=3).contains(&len) || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Self::Unassigned;
+        }
+        buf[..len].copy_from_slice(code.as_bytes());
+        buf.make_ascii_uppercase();
+        let reserved = if len == 2 {
+            Self::reserved_alpha2([buf[0], buf[1]])
+        } else {
+            Self::reserved_alpha3(buf)
+        };
+        if let Some(status) = reserved {
+            return status;
+        }
+        let country = if len == 2 {
+            Country::from_alpha2(code)
+        } else {
+            Country::from_alpha3(code)
+        };
+        country.map_or(Self::Unassigned, |c| c.code_status())
+    }
+
+    const fn reserved_alpha2(code: [u8; 2]) -> Option<Self> {
+        Some(match code {
+            [b'A', b'A'] | [b'Q', b'M'..=b'Z'] | [b'X', _] | [b'Z', b'Z'] => Self::UserAssigned,
+            [b'A', b'C'] => Self::ExceptionallyReserved("Ascension Island"),
+            [b'C', b'P'] => Self::ExceptionallyReserved("Clipperton Island"),
+            [b'C', b'Q'] => Self::ExceptionallyReserved("Sark"),
+            [b'D', b'G'] => Self::ExceptionallyReserved("Diego Garcia"),
+            [b'E', b'A'] => Self::ExceptionallyReserved("Ceuta, Melilla"),
+            [b'E', b'U'] => Self::ExceptionallyReserved("European Union"),
+            [b'E', b'Z'] => Self::ExceptionallyReserved("Eurozone"),
+            [b'F', b'X'] => Self::ExceptionallyReserved("France, Metropolitan"),
+            [b'I', b'C'] => Self::ExceptionallyReserved("Canary Islands"),
+            [b'S', b'U'] => Self::ExceptionallyReserved("USSR"),
+            [b'T', b'A'] => Self::ExceptionallyReserved("Tristan da Cunha"),
+            [b'U', b'K'] => Self::ExceptionallyReserved("United Kingdom"),
+            [b'U', b'N'] => Self::ExceptionallyReserved("United Nations"),
+            [b'A', b'N'] => Self::TransitionallyReserved("Netherlands Antilles"),
+            [b'B', b'U'] => Self::TransitionallyReserved("Burma"),
+            [b'C', b'S'] => Self::TransitionallyReserved("Serbia and Montenegro"),
+            [b'N', b'T'] => Self::TransitionallyReserved("Neutral Zone"),
+            [b'T', b'P'] => Self::TransitionallyReserved("East Timor"),
+            [b'Y', b'U'] => Self::TransitionallyReserved("Yugoslavia"),
+            [b'Z', b'R'] => Self::TransitionallyReserved("Zaire"),
+            [b'D', b'Y'] => Self::IndeterminatelyReserved("Benin"),
+            [b'E', b'W'] => Self::IndeterminatelyReserved("Estonia"),
+            [b'F', b'L'] => Self::IndeterminatelyReserved("Liechtenstein"),
+            [b'J', b'A'] => Self::IndeterminatelyReserved("Jamaica"),
+            [b'L', b'F'] => Self::IndeterminatelyReserved("Libya Fezzan"),
+            [b'P', b'I'] | [b'R', b'P'] => Self::IndeterminatelyReserved("Philippines"),
+            [b'R', b'A'] => Self::IndeterminatelyReserved("Argentina"),
+            [b'R', b'B'] => Self::IndeterminatelyReserved("Bolivia, Botswana"),
+            [b'R', b'C'] => Self::IndeterminatelyReserved("China"),
+            [b'R', b'H'] => Self::IndeterminatelyReserved("Haiti"),
+            [b'R', b'I'] => Self::IndeterminatelyReserved("Indonesia"),
+            [b'R', b'L'] => Self::IndeterminatelyReserved("Lebanon"),
+            [b'R', b'M'] => Self::IndeterminatelyReserved("Madagascar"),
+            [b'R', b'N'] => Self::IndeterminatelyReserved("Niger"),
+            [b'W', b'G'] => Self::IndeterminatelyReserved("Grenada"),
+            [b'W', b'L'] => Self::IndeterminatelyReserved("Saint Lucia"),
+            [b'W', b'V'] => Self::IndeterminatelyReserved("Saint Vincent"),
+            [b'Y', b'V'] => Self::IndeterminatelyReserved("Venezuela"),
+            _ => return None,
+        })
+    }
+
+    const fn reserved_alpha3(code: [u8; 3]) -> Option<Self> {
+        Some(match code {
+            [b'A', b'A', _] | [b'Q', b'M'..=b'Z', _] | [b'X', _, _] | [b'Z', b'Z', _] => {
+                Self::UserAssigned
+            }
+            [b'A', b'N', b'T'] => Self::TransitionallyReserved("Netherlands Antilles"),
+            [b'B', b'U', b'R'] => Self::TransitionallyReserved("Burma"),
+            [b'S', b'C', b'G'] => Self::TransitionallyReserved("Serbia and Montenegro"),
+            [b'N', b'T', b'Z'] => Self::TransitionallyReserved("Neutral Zone"),
+            [b'T', b'M', b'P'] => Self::TransitionallyReserved("East Timor"),
+            [b'Y', b'U', b'G'] => Self::TransitionallyReserved("Yugoslavia"),
+            [b'Z', b'A', b'R'] => Self::TransitionallyReserved("Zaire"),
+            _ => return None,
+        })
+    }
+}
+
+impl Country {
+    /// The assignment status of this country's codes.
+    /// This is [`CodeStatus::UserAssigned`] for Kosovo and territories created with
+    /// [`Country::user_assigned`] and [`CodeStatus::OfficiallyAssigned`] for every other country.
+    ///
+    /// ```
+    /// use celes::{CodeStatus, Country};
+    ///
+    /// assert_eq!(CodeStatus::OfficiallyAssigned, Country::germany().code_status());
+    /// assert_eq!(CodeStatus::UserAssigned, Country::kosovo().code_status());
+    /// ```
+    #[must_use]
+    pub fn code_status(&self) -> CodeStatus {
+        let value = self.value();
+        if value == 383 || value >= USER_ASSIGNED_VALUES {
+            CodeStatus::UserAssigned
+        } else {
+            CodeStatus::OfficiallyAssigned
+        }
+    }
+
+    /// Define a territory in the user-assigned ranges, like `XA` for "International Waters".
+    /// The alpha2 and alpha3 codes must be uppercase user-assigned codes other than
+    /// Kosovo's `XK` and `XKX`, and the value must be between 900 and 999 so the
+    /// territory can never collide with a real ISO code.
+    ///
+    /// Territories are stored for the life of the process. Register one with
+    /// `Country::register_user_assigned` so it round-trips through parsing and serde.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first field outside the user-assigned ranges.
+    ///
+    /// ```
+    /// use celes::{CodeStatus, Country};
+    ///
+    /// let international_waters = Country::user_assigned("901", 901, "XA", "XAA", "International Waters")
+    ///     .expect("user-assigned");
+    /// assert_eq!(CodeStatus::UserAssigned, international_waters.code_status());
+    /// assert!(Country::user_assigned("276", 276, "DE", "DEU", "Germany").is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn user_assigned(
+        code: &'static str,
+        value: usize,
+        alpha2: &'static str,
+        alpha3: &'static str,
+        long_name: &'static str,
+    ) -> Result<Self, &'static str> {
+        let c = code.as_bytes();
+        if c.len() != 3
+            || !c[0].is_ascii_digit()
+            || !c[1].is_ascii_digit()
+            || !c[2].is_ascii_digit()
+        {
+            return Err("code must be three digits");
+        }
+        let parsed =
+            (c[0] - b'0') as usize * 100 + (c[1] - b'0') as usize * 10 + (c[2] - b'0') as usize;
+        if parsed != value {
+            return Err("code must match value");
+        }
+        if value < USER_ASSIGNED_VALUES {
+            return Err("value must be between 900 and 999");
+        }
+        let a2 = alpha2.as_bytes();
+        if a2.len() != 2
+            || matches!(a2, b"XK")
+            || !matches!(
+                CodeStatus::reserved_alpha2([a2[0], a2[1]]),
+                Some(CodeStatus::UserAssigned)
+            )
+        {
+            return Err("alpha2 must be an uppercase user-assigned code");
+        }
+        let a3 = alpha3.as_bytes();
+        if a3.len() != 3
+            || matches!(a3, b"XKX")
+            || !matches!(
+                CodeStatus::reserved_alpha3([a3[0], a3[1], a3[2]]),
+                Some(CodeStatus::UserAssigned)
+            )
+        {
+            return Err("alpha3 must be an uppercase user-assigned code");
+        }
+        if long_name.is_empty() {
+            return Err("long_name must not be empty");
+        }
+        #[allow(deprecated)]
+        runtime::register(CountryFields {
+            code,
+            value,
+            alpha2,
+            alpha3,
+            long_name,
+            aliases: CountryTable::EMPTY,
+        })
+    }
+
+    /// True if this country's codes are officially assigned by ISO 3166-1
+    #[must_use]
+    pub fn is_officially_assigned(&self) -> bool {
+        matches!(self.code_status(), CodeStatus::OfficiallyAssigned)
+    }
+}
+
+/// The result of resolving an alpha2 code that may be a conventional, non-ISO code
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConventionalCode {
+    /// The ISO country the code resolved to
+    pub country: Country,
+    /// True if the code is not the ISO 3166-1 alpha2 code for the country,
+    /// like Eurostat's `EL` for Greece or `UK` for the United Kingdom
+    pub non_standard: bool,
+}
+
+impl Country {
+    /// Create a country from an alpha2 code, also accepting conventional
+    /// non-ISO codes such as `EL` for Greece and `UK` for the United Kingdom
+    /// as used by EU institutions and many legacy systems.
+    /// [`Country::from_alpha2`] remains strict and rejects these codes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `"invalid alpha2"` if the code is neither an ISO nor a conventional code.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// let res = Country::from_alpha2_conventional("UK").expect("conventional code");
+    /// assert_eq!(Country::the_united_kingdom_of_great_britain_and_northern_ireland(), res.country);
+    /// assert!(res.non_standard);
+    ///
+    /// let res = Country::from_alpha2_conventional("gr").expect("iso code");
+    /// assert_eq!(Country::greece(), res.country);
+    /// assert!(!res.non_standard);
+    ///
+    /// assert!(Country::from_alpha2("EL").is_err());
+    /// ```
+    pub fn from_alpha2_conventional<A: AsRef<str>>(
+        alpha2: A,
+    ) -> Result<ConventionalCode, &'static str> {
+        let alpha2 = alpha2.as_ref();
+        let country = if alpha2.eq_ignore_ascii_case("EL") {
+            Self::greece()
+        } else if alpha2.eq_ignore_ascii_case("UK") {
+            Self::the_united_kingdom_of_great_britain_and_northern_ireland()
+        } else {
+            return Self::from_alpha2(alpha2).map(|country| ConventionalCode {
+                country,
+                non_standard: false,
+            });
+        };
+        Ok(ConventionalCode {
+            country,
+            non_standard: true,
+        })
+    }
+}
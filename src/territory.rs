@@ -0,0 +1,87 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Registration of user-assigned territories so they can be parsed and deserialized
+
+use crate::{Country, overrides::Field};
+use std::sync::{PoisonError, RwLock};
+
+static TERRITORIES: RwLock<Vec<Country>> = RwLock::new(Vec::new());
+
+impl Country {
+    /// Register a territory created with [`Country::user_assigned`] so that the
+    /// `from_*` lookups, `FromStr` and serde recognize it.
+    /// Registering the same territory again has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the territory is outside the user-assigned ranges or one
+    /// of its codes is already registered to a different territory.
+    ///
+    /// ```
+    /// use celes::Country;
+    /// use core::str::FromStr;
+    ///
+    /// let unknown = Country::user_assigned("999", 999, "XZ", "XZZ", "Unknown").expect("user-assigned");
+    /// Country::register_user_assigned(unknown).expect("not registered yet");
+    /// assert_eq!(unknown, Country::from_str("XZ").expect("registered"));
+    /// assert_eq!("\"XZ\"", serde_json::to_string(&unknown).expect("serialize"));
+    /// assert_eq!(unknown, serde_json::from_str::<Country>("\"XZ\"").expect("deserialize"));
+    /// ```
+    pub fn register_user_assigned(territory: Self) -> Result<(), &'static str> {
+        let territory = Self::user_assigned(
+            territory.code(),
+            territory.value(),
+            territory.alpha2(),
+            territory.alpha3(),
+            territory.long_name(),
+        )?;
+        let mut territories = TERRITORIES.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(existing) = territories.iter().find(|c| {
+            c.value() == territory.value()
+                || c.alpha2() == territory.alpha2()
+                || c.alpha3() == territory.alpha3()
+        }) {
+            return if existing.value() == territory.value()
+                && existing.alpha2() == territory.alpha2()
+                && existing.alpha3() == territory.alpha3()
+                && existing.long_name() == territory.long_name()
+            {
+                Ok(())
+            } else {
+                Err("user-assigned code is already registered")
+            };
+        }
+        territories.push(territory);
+        Ok(())
+    }
+
+    /// Remove a registered territory by its alpha2 code
+    pub fn unregister_user_assigned<A: AsRef<str>>(alpha2: A) -> Option<Self> {
+        let mut territories = TERRITORIES.write().unwrap_or_else(PoisonError::into_inner);
+        let index = territories
+            .iter()
+            .position(|c| c.alpha2().eq_ignore_ascii_case(alpha2.as_ref()))?;
+        Some(territories.remove(index))
+    }
+
+    /// The registered user-assigned territories
+    #[must_use]
+    pub fn registered_user_assigned() -> Vec<Self> {
+        TERRITORIES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// Find a registered territory matching `field`
+pub(crate) fn find(field: Field<'_>, key: &str) -> Option<Country> {
+    TERRITORIES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|c| field.matches_country(key, **c))
+        .copied()
+}
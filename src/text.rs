@@ -0,0 +1,970 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! Find countries mentioned in free text
+//!
+//! Text is split into words. Starting at each word, runs of words joined by
+//! spaces, hyphens, apostrophes or dots are folded to lowercase without spaces
+//! or accents and matched against one sorted table of every name, alias and
+//! demonym. The table is walked like a trie, one word at a time, and the longest
+//! match wins, so "Viet Nam", "viet nam", "Côte d'Ivoire" and "St. Lucia" are all
+//! found. Matches never start or end inside a word.
+//!
+//! Only the compiled-in names are matched. With the `std` feature a country
+//! replaced by an installed override is reported as the override.
+//!
+//! ```
+//! use celes::{Country, text::find_countries};
+//!
+//! let text = "Shipping from Viet Nam to the UK via Singapore";
+//! let mentions: Vec<_> = find_countries(text).collect();
+//! assert_eq!(3, mentions.len());
+//! assert_eq!(Country::vietnam(), mentions[0].0);
+//! assert_eq!(14..22, mentions[0].1);
+//! assert_eq!("Viet Nam", mentions[0].2);
+//! assert_eq!(Country::the_united_kingdom_of_great_britain_and_northern_ireland(), mentions[1].0);
+//! assert_eq!(Country::singapore(), mentions[2].0);
+//! ```
+
+use crate::Country;
+use core::{iter::FusedIterator, ops::Range};
+use phf::{Map, phf_map};
+
+/// Abbreviations matched in any case even when codes are not
+static ABBREVIATIONS: Map<&'static str, Country> = phf_map! {
+    "UK" => Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    "USA" => Country::the_united_states_of_america(),
+    "UAE" => Country::the_united_arab_emirates(),
+    "DRC" => Country::the_democratic_republic_of_the_congo(),
+};
+
+/// The names and aliases of `Country::from_name` and `Country::from_alias`
+/// followed by demonyms, keyed by their lowercase letters without accents.
+/// Sorted so the phrases starting with some text are adjacent.
+const PHRASES: &[(&str, Country)] = &[
+    ("afghan", Country::afghanistan()),
+    ("afghanistan", Country::afghanistan()),
+    ("alandislands", Country::aland_islands()),
+    ("albania", Country::albania()),
+    ("albanian", Country::albania()),
+    ("algeria", Country::algeria()),
+    ("algerian", Country::algeria()),
+    ("america", Country::the_united_states_of_america()),
+    ("american", Country::the_united_states_of_america()),
+    ("americansamoa", Country::american_samoa()),
+    ("andorra", Country::andorra()),
+    ("andorran", Country::andorra()),
+    ("angola", Country::angola()),
+    ("angolan", Country::angola()),
+    ("anguilla", Country::anguilla()),
+    ("antarctica", Country::antarctica()),
+    ("antiguaandbarbuda", Country::antigua_and_barbuda()),
+    ("argentina", Country::argentina()),
+    ("argentine", Country::argentina()),
+    ("argentinian", Country::argentina()),
+    ("armenia", Country::armenia()),
+    ("armenian", Country::armenia()),
+    ("aruba", Country::aruba()),
+    (
+        "ascensionandtristandacunhasainthelena",
+        Country::ascension_and_tristan_da_cunha_saint_helena(),
+    ),
+    ("australia", Country::australia()),
+    ("australian", Country::australia()),
+    ("austria", Country::austria()),
+    ("austrian", Country::austria()),
+    ("azerbaijan", Country::azerbaijan()),
+    ("azerbaijani", Country::azerbaijan()),
+    ("bahamas", Country::the_bahamas()),
+    ("bahamian", Country::the_bahamas()),
+    ("bahrain", Country::bahrain()),
+    ("bahraini", Country::bahrain()),
+    ("bangladesh", Country::bangladesh()),
+    ("bangladeshi", Country::bangladesh()),
+    ("barbadian", Country::barbados()),
+    ("barbados", Country::barbados()),
+    ("belarus", Country::belarus()),
+    ("belarusian", Country::belarus()),
+    ("belgian", Country::belgium()),
+    ("belgium", Country::belgium()),
+    ("belize", Country::belize()),
+    ("belizean", Country::belize()),
+    ("benin", Country::benin()),
+    ("beninese", Country::benin()),
+    ("bermuda", Country::bermuda()),
+    ("bhutan", Country::bhutan()),
+    ("bhutanese", Country::bhutan()),
+    ("bissauguinean", Country::guinea_bissau()),
+    (
+        "bolivarianrepublicofvenezuela",
+        Country::bolivarian_republic_of_venezuela(),
+    ),
+    ("bolivia", Country::bolivia()),
+    ("bolivian", Country::bolivia()),
+    ("bonaire", Country::bonaire()),
+    ("bosnia", Country::bosnia_and_herzegovina()),
+    ("bosniaandherzegovina", Country::bosnia_and_herzegovina()),
+    ("bosnian", Country::bosnia_and_herzegovina()),
+    ("botswana", Country::botswana()),
+    ("botswanan", Country::botswana()),
+    ("bouvetisland", Country::bouvet_island()),
+    ("brazil", Country::brazil()),
+    ("brazilian", Country::brazil()),
+    (
+        "british",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    (
+        "britishindianoceanterritory",
+        Country::british_indian_ocean_territory(),
+    ),
+    ("britishvirginislands", Country::british_virgin_islands()),
+    ("brunei", Country::brunei_darussalam()),
+    ("bruneian", Country::brunei_darussalam()),
+    ("bruneidarussalam", Country::brunei_darussalam()),
+    ("bulgaria", Country::bulgaria()),
+    ("bulgarian", Country::bulgaria()),
+    ("burkina", Country::burkina_faso()),
+    ("burkinabe", Country::burkina_faso()),
+    ("burkinafaso", Country::burkina_faso()),
+    ("burma", Country::myanmar()),
+    ("burmese", Country::myanmar()),
+    ("burundi", Country::burundi()),
+    ("burundian", Country::burundi()),
+    ("caboverde", Country::cabo_verde()),
+    ("cambodia", Country::cambodia()),
+    ("cambodian", Country::cambodia()),
+    ("cameroon", Country::cameroon()),
+    ("cameroonian", Country::cameroon()),
+    ("canada", Country::canada()),
+    ("canadian", Country::canada()),
+    ("capeverde", Country::cabo_verde()),
+    ("capeverdean", Country::cabo_verde()),
+    ("caymanislands", Country::the_cayman_islands()),
+    ("centralafrican", Country::the_central_african_republic()),
+    (
+        "centralafricanrepublic",
+        Country::the_central_african_republic(),
+    ),
+    ("chad", Country::chad()),
+    ("chadian", Country::chad()),
+    ("chile", Country::chile()),
+    ("chilean", Country::chile()),
+    ("china", Country::china()),
+    ("chinese", Country::china()),
+    ("christmasisland", Country::christmas_island()),
+    ("cocosislands", Country::the_cocos_keeling_islands()),
+    ("colombia", Country::colombia()),
+    ("colombian", Country::colombia()),
+    ("comoran", Country::the_comoros()),
+    ("comoros", Country::the_comoros()),
+    ("congo", Country::the_congo()),
+    ("cookislands", Country::the_cook_islands()),
+    ("costarica", Country::costa_rica()),
+    ("costarican", Country::costa_rica()),
+    ("cotedivoire", Country::coted_ivoire()),
+    ("croatia", Country::croatia()),
+    ("croatian", Country::croatia()),
+    ("cuba", Country::cuba()),
+    ("cuban", Country::cuba()),
+    ("curacao", Country::curacao()),
+    ("cypriot", Country::cyprus()),
+    ("cyprus", Country::cyprus()),
+    ("czech", Country::czechia()),
+    ("czechia", Country::czechia()),
+    ("czechrepublic", Country::czechia()),
+    ("danish", Country::denmark()),
+    (
+        "democraticpeoplesrepublicofkorea",
+        Country::the_democratic_peoples_republic_of_korea(),
+    ),
+    (
+        "democraticrepublicofthecongo",
+        Country::the_democratic_republic_of_the_congo(),
+    ),
+    ("denmark", Country::denmark()),
+    ("djibouti", Country::djibouti()),
+    ("djiboutian", Country::djibouti()),
+    ("dominica", Country::dominica()),
+    ("dominican", Country::the_dominican_republic()),
+    ("dominicanrepublic", Country::the_dominican_republic()),
+    ("dutch", Country::the_netherlands()),
+    ("dutchpartsintmaarten", Country::dutch_part_sint_maarten()),
+    ("easttimor", Country::timor_leste()),
+    ("ecuador", Country::ecuador()),
+    ("ecuadorian", Country::ecuador()),
+    ("egypt", Country::egypt()),
+    ("egyptian", Country::egypt()),
+    ("elsalvador", Country::el_salvador()),
+    ("emirati", Country::the_united_arab_emirates()),
+    (
+        "england",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    ("equatorialguinea", Country::equatorial_guinea()),
+    ("equatorialguinean", Country::equatorial_guinea()),
+    ("eritrea", Country::eritrea()),
+    ("eritrean", Country::eritrea()),
+    ("estonia", Country::estonia()),
+    ("estonian", Country::estonia()),
+    ("eswatini", Country::eswatini()),
+    ("ethiopia", Country::ethiopia()),
+    ("ethiopian", Country::ethiopia()),
+    ("falklandislands", Country::the_falkland_islands_malvinas()),
+    ("faroeislands", Country::the_faroe_islands()),
+    (
+        "federatedstatesofmicronesia",
+        Country::federated_states_of_micronesia(),
+    ),
+    ("fiji", Country::fiji()),
+    ("fijian", Country::fiji()),
+    ("filipino", Country::the_philippines()),
+    ("finland", Country::finland()),
+    ("finnish", Country::finland()),
+    ("france", Country::france()),
+    ("french", Country::france()),
+    ("frenchguiana", Country::french_guiana()),
+    ("frenchpartsaintmartin", Country::french_part_saint_martin()),
+    ("frenchpolynesia", Country::french_polynesia()),
+    (
+        "frenchsouthernterritories",
+        Country::the_french_southern_territories(),
+    ),
+    ("gabon", Country::gabon()),
+    ("gabonese", Country::gabon()),
+    ("gambia", Country::the_gambia()),
+    ("gambian", Country::the_gambia()),
+    ("georgia", Country::georgia()),
+    ("georgian", Country::georgia()),
+    ("german", Country::germany()),
+    ("germany", Country::germany()),
+    ("ghana", Country::ghana()),
+    ("ghanaian", Country::ghana()),
+    ("gibraltar", Country::gibraltar()),
+    (
+        "greatbritain",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    ("greece", Country::greece()),
+    ("greek", Country::greece()),
+    ("greenland", Country::greenland()),
+    ("greenlandic", Country::greenland()),
+    ("grenada", Country::grenada()),
+    ("grenadian", Country::grenada()),
+    ("guadeloupe", Country::guadeloupe()),
+    ("guam", Country::guam()),
+    ("guatemala", Country::guatemala()),
+    ("guatemalan", Country::guatemala()),
+    ("guernsey", Country::guernsey()),
+    ("guinea", Country::guinea()),
+    ("guineabissau", Country::guinea_bissau()),
+    ("guinean", Country::guinea()),
+    ("guyana", Country::guyana()),
+    ("guyanese", Country::guyana()),
+    ("haiti", Country::haiti()),
+    ("haitian", Country::haiti()),
+    ("heardisland", Country::heard_island_and_mc_donald_islands()),
+    (
+        "heardislandandmcdonaldislands",
+        Country::heard_island_and_mc_donald_islands(),
+    ),
+    ("herzegovina", Country::bosnia_and_herzegovina()),
+    ("holland", Country::the_netherlands()),
+    ("holysee", Country::the_holy_see()),
+    ("honduran", Country::honduras()),
+    ("honduras", Country::honduras()),
+    ("hongkong", Country::hong_kong()),
+    ("hungarian", Country::hungary()),
+    ("hungary", Country::hungary()),
+    ("iceland", Country::iceland()),
+    ("icelandic", Country::iceland()),
+    ("india", Country::india()),
+    ("indian", Country::india()),
+    ("indonesia", Country::indonesia()),
+    ("indonesian", Country::indonesia()),
+    ("iran", Country::islamic_republic_of_iran()),
+    ("iranian", Country::islamic_republic_of_iran()),
+    ("iraq", Country::iraq()),
+    ("iraqi", Country::iraq()),
+    ("ireland", Country::ireland()),
+    ("irish", Country::ireland()),
+    ("islamicrepublicofiran", Country::islamic_republic_of_iran()),
+    ("isleofman", Country::isle_of_man()),
+    ("israel", Country::israel()),
+    ("israeli", Country::israel()),
+    ("italian", Country::italy()),
+    ("italy", Country::italy()),
+    ("ivorian", Country::coted_ivoire()),
+    ("ivorycoast", Country::coted_ivoire()),
+    ("jamaica", Country::jamaica()),
+    ("jamaican", Country::jamaica()),
+    ("japan", Country::japan()),
+    ("japanese", Country::japan()),
+    ("jersey", Country::jersey()),
+    ("jordan", Country::jordan()),
+    ("jordanian", Country::jordan()),
+    ("kazakh", Country::kazakhstan()),
+    ("kazakhstan", Country::kazakhstan()),
+    ("keelingislands", Country::the_cocos_keeling_islands()),
+    ("kenya", Country::kenya()),
+    ("kenyan", Country::kenya()),
+    ("kiribati", Country::kiribati()),
+    ("kosovar", Country::kosovo()),
+    ("kosovo", Country::kosovo()),
+    ("kuwait", Country::kuwait()),
+    ("kuwaiti", Country::kuwait()),
+    ("kyrgyz", Country::kyrgyzstan()),
+    ("kyrgyzstan", Country::kyrgyzstan()),
+    ("lao", Country::the_lao_peoples_democratic_republic()),
+    (
+        "laopeoplesdemocraticrepublic",
+        Country::the_lao_peoples_democratic_republic(),
+    ),
+    ("laos", Country::the_lao_peoples_democratic_republic()),
+    ("laotian", Country::the_lao_peoples_democratic_republic()),
+    ("latvia", Country::latvia()),
+    ("latvian", Country::latvia()),
+    ("lebanese", Country::lebanon()),
+    ("lebanon", Country::lebanon()),
+    ("lesotho", Country::lesotho()),
+    ("liberia", Country::liberia()),
+    ("liberian", Country::liberia()),
+    ("libya", Country::libya()),
+    ("libyan", Country::libya()),
+    ("liechtenstein", Country::liechtenstein()),
+    ("lithuania", Country::lithuania()),
+    ("lithuanian", Country::lithuania()),
+    ("luxembourg", Country::luxembourg()),
+    ("luxembourgish", Country::luxembourg()),
+    ("macao", Country::macao()),
+    ("macau", Country::macao()),
+    ("macedonia", Country::republic_of_north_macedonia()),
+    ("macedonian", Country::republic_of_north_macedonia()),
+    ("madagascar", Country::madagascar()),
+    ("malagasy", Country::madagascar()),
+    ("malawi", Country::malawi()),
+    ("malawian", Country::malawi()),
+    ("malaysia", Country::malaysia()),
+    ("malaysian", Country::malaysia()),
+    ("maldives", Country::maldives()),
+    ("maldivian", Country::maldives()),
+    ("mali", Country::mali()),
+    ("malian", Country::mali()),
+    ("malta", Country::malta()),
+    ("maltese", Country::malta()),
+    ("malvinas", Country::the_falkland_islands_malvinas()),
+    ("marshallislands", Country::the_marshall_islands()),
+    ("martinique", Country::martinique()),
+    ("mauritania", Country::mauritania()),
+    ("mauritanian", Country::mauritania()),
+    ("mauritian", Country::mauritius()),
+    ("mauritius", Country::mauritius()),
+    ("mayotte", Country::mayotte()),
+    (
+        "mcdonaldislands",
+        Country::heard_island_and_mc_donald_islands(),
+    ),
+    ("mexican", Country::mexico()),
+    ("mexico", Country::mexico()),
+    ("micronesia", Country::federated_states_of_micronesia()),
+    ("micronesian", Country::federated_states_of_micronesia()),
+    ("moldova", Country::the_republic_of_moldova()),
+    ("moldovan", Country::the_republic_of_moldova()),
+    ("monaco", Country::monaco()),
+    ("monegasque", Country::monaco()),
+    ("mongolia", Country::mongolia()),
+    ("mongolian", Country::mongolia()),
+    ("montenegrin", Country::montenegro()),
+    ("montenegro", Country::montenegro()),
+    ("montserrat", Country::montserrat()),
+    ("moroccan", Country::morocco()),
+    ("morocco", Country::morocco()),
+    ("mozambican", Country::mozambique()),
+    ("mozambique", Country::mozambique()),
+    ("myanmar", Country::myanmar()),
+    ("namibia", Country::namibia()),
+    ("namibian", Country::namibia()),
+    ("nauru", Country::nauru()),
+    ("nepal", Country::nepal()),
+    ("nepalese", Country::nepal()),
+    ("nepali", Country::nepal()),
+    ("netherlands", Country::the_netherlands()),
+    ("newcaledonia", Country::new_caledonia()),
+    ("newzealand", Country::new_zealand()),
+    ("newzealander", Country::new_zealand()),
+    ("nicaragua", Country::nicaragua()),
+    ("nicaraguan", Country::nicaragua()),
+    ("niger", Country::the_niger()),
+    ("nigeria", Country::nigeria()),
+    ("nigerian", Country::nigeria()),
+    ("nigerien", Country::the_niger()),
+    ("niue", Country::niue()),
+    ("norfolkisland", Country::norfolk_island()),
+    (
+        "northernireland",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    (
+        "northernmarianaislands",
+        Country::the_northern_mariana_islands(),
+    ),
+    (
+        "northkorea",
+        Country::the_democratic_peoples_republic_of_korea(),
+    ),
+    (
+        "northkorean",
+        Country::the_democratic_peoples_republic_of_korea(),
+    ),
+    ("northmacedonia", Country::republic_of_north_macedonia()),
+    ("northmacedonian", Country::republic_of_north_macedonia()),
+    ("norway", Country::norway()),
+    ("norwegian", Country::norway()),
+    ("oman", Country::oman()),
+    ("omani", Country::oman()),
+    ("pakistan", Country::pakistan()),
+    ("pakistani", Country::pakistan()),
+    ("palau", Country::palau()),
+    ("palauan", Country::palau()),
+    ("palestine", Country::state_of_palestine()),
+    ("palestinian", Country::state_of_palestine()),
+    ("panama", Country::panama()),
+    ("panamanian", Country::panama()),
+    ("papuanewguinea", Country::papua_new_guinea()),
+    ("papuanewguinean", Country::papua_new_guinea()),
+    ("paraguay", Country::paraguay()),
+    ("paraguayan", Country::paraguay()),
+    ("peru", Country::peru()),
+    ("peruvian", Country::peru()),
+    ("philippine", Country::the_philippines()),
+    ("philippines", Country::the_philippines()),
+    ("pitcairn", Country::pitcairn()),
+    ("poland", Country::poland()),
+    ("polish", Country::poland()),
+    ("portugal", Country::portugal()),
+    ("portuguese", Country::portugal()),
+    ("puertorican", Country::puerto_rico()),
+    ("puertorico", Country::puerto_rico()),
+    ("qatar", Country::qatar()),
+    ("qatari", Country::qatar()),
+    ("republicofkorea", Country::the_republic_of_korea()),
+    ("republicofmoldova", Country::the_republic_of_moldova()),
+    (
+        "republicofnorthmacedonia",
+        Country::republic_of_north_macedonia(),
+    ),
+    ("reunion", Country::reunion()),
+    ("romania", Country::romania()),
+    ("romanian", Country::romania()),
+    ("russia", Country::the_russian_federation()),
+    ("russian", Country::the_russian_federation()),
+    ("russianfederation", Country::the_russian_federation()),
+    ("rwanda", Country::rwanda()),
+    ("rwandan", Country::rwanda()),
+    ("saintbarthelemy", Country::saint_barthelemy()),
+    (
+        "sainthelena",
+        Country::ascension_and_tristan_da_cunha_saint_helena(),
+    ),
+    ("saintkittsandnevis", Country::saint_kitts_and_nevis()),
+    ("saintlucia", Country::saint_lucia()),
+    ("saintlucian", Country::saint_lucia()),
+    ("saintmaarten", Country::dutch_part_sint_maarten()),
+    ("saintmartin", Country::french_part_saint_martin()),
+    ("saintpierre", Country::saint_pierre_and_miquelon()),
+    (
+        "saintpierreandmiquelon",
+        Country::saint_pierre_and_miquelon(),
+    ),
+    ("saintvincent", Country::saint_vincent_and_the_grenadines()),
+    (
+        "saintvincentandthegrenadines",
+        Country::saint_vincent_and_the_grenadines(),
+    ),
+    ("salvadoran", Country::el_salvador()),
+    ("sammarinese", Country::san_marino()),
+    ("samoa", Country::samoa()),
+    ("samoan", Country::samoa()),
+    ("sanmarino", Country::san_marino()),
+    ("saotome", Country::sao_tome_and_principe()),
+    ("saotomeandprincipe", Country::sao_tome_and_principe()),
+    ("saudi", Country::saudi_arabia()),
+    ("saudiarabia", Country::saudi_arabia()),
+    ("saudiarabian", Country::saudi_arabia()),
+    (
+        "scotland",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    ("senegal", Country::senegal()),
+    ("senegalese", Country::senegal()),
+    ("serbia", Country::serbia()),
+    ("serbian", Country::serbia()),
+    ("seychelles", Country::seychelles()),
+    ("seychellois", Country::seychelles()),
+    ("sierraleone", Country::sierra_leone()),
+    ("sierraleonean", Country::sierra_leone()),
+    ("singapore", Country::singapore()),
+    ("singaporean", Country::singapore()),
+    ("slovak", Country::slovakia()),
+    ("slovakia", Country::slovakia()),
+    ("slovenia", Country::slovenia()),
+    ("slovenian", Country::slovenia()),
+    ("solomonislander", Country::solomon_islands()),
+    ("solomonislands", Country::solomon_islands()),
+    ("somali", Country::somalia()),
+    ("somalia", Country::somalia()),
+    ("southafrica", Country::south_africa()),
+    ("southafrican", Country::south_africa()),
+    (
+        "southgeorgia",
+        Country::south_georgia_and_the_south_sandwich_islands(),
+    ),
+    (
+        "southgeorgiaandthesouthsandwichislands",
+        Country::south_georgia_and_the_south_sandwich_islands(),
+    ),
+    ("southkorea", Country::the_republic_of_korea()),
+    ("southkorean", Country::the_republic_of_korea()),
+    (
+        "southsandwichislands",
+        Country::south_georgia_and_the_south_sandwich_islands(),
+    ),
+    ("southsudan", Country::south_sudan()),
+    ("southsudanese", Country::south_sudan()),
+    ("spain", Country::spain()),
+    ("spanish", Country::spain()),
+    ("srilanka", Country::sri_lanka()),
+    ("srilankan", Country::sri_lanka()),
+    ("stateofpalestine", Country::state_of_palestine()),
+    ("stbarthelemy", Country::saint_barthelemy()),
+    (
+        "sthelena",
+        Country::ascension_and_tristan_da_cunha_saint_helena(),
+    ),
+    ("stkitts", Country::saint_kitts_and_nevis()),
+    ("stlucia", Country::saint_lucia()),
+    ("stmaarten", Country::dutch_part_sint_maarten()),
+    ("stmartin", Country::french_part_saint_martin()),
+    ("stpierre", Country::saint_pierre_and_miquelon()),
+    ("stvincent", Country::saint_vincent_and_the_grenadines()),
+    ("sudan", Country::the_sudan()),
+    ("sudanese", Country::the_sudan()),
+    ("suriname", Country::suriname()),
+    ("surinamese", Country::suriname()),
+    ("svalbardandjanmayen", Country::svalbard_and_jan_mayen()),
+    ("swazi", Country::eswatini()),
+    ("swaziland", Country::eswatini()),
+    ("sweden", Country::sweden()),
+    ("swedish", Country::sweden()),
+    ("swiss", Country::switzerland()),
+    ("switzerland", Country::switzerland()),
+    ("syria", Country::syrian_arab_republic()),
+    ("syrian", Country::syrian_arab_republic()),
+    ("syrianarabrepublic", Country::syrian_arab_republic()),
+    ("taiwan", Country::taiwan()),
+    ("taiwanese", Country::taiwan()),
+    ("tajik", Country::tajikistan()),
+    ("tajikistan", Country::tajikistan()),
+    ("tanzania", Country::united_republic_of_tanzania()),
+    ("tanzanian", Country::united_republic_of_tanzania()),
+    ("thai", Country::thailand()),
+    ("thailand", Country::thailand()),
+    ("thebahamas", Country::the_bahamas()),
+    ("thecaymanislands", Country::the_cayman_islands()),
+    (
+        "thecentralafricanrepublic",
+        Country::the_central_african_republic(),
+    ),
+    (
+        "thecocoskeelingislands",
+        Country::the_cocos_keeling_islands(),
+    ),
+    ("thecomoros", Country::the_comoros()),
+    ("thecongo", Country::the_congo()),
+    ("thecookislands", Country::the_cook_islands()),
+    (
+        "thedemocraticpeoplesrepublicofkorea",
+        Country::the_democratic_peoples_republic_of_korea(),
+    ),
+    (
+        "thedemocraticrepublicofthecongo",
+        Country::the_democratic_republic_of_the_congo(),
+    ),
+    ("thedominicanrepublic", Country::the_dominican_republic()),
+    (
+        "thefalklandislandsmalvinas",
+        Country::the_falkland_islands_malvinas(),
+    ),
+    ("thefaroeislands", Country::the_faroe_islands()),
+    (
+        "thefrenchsouthernterritories",
+        Country::the_french_southern_territories(),
+    ),
+    ("thegambia", Country::the_gambia()),
+    ("theholysee", Country::the_holy_see()),
+    (
+        "thelaopeoplesdemocraticrepublic",
+        Country::the_lao_peoples_democratic_republic(),
+    ),
+    ("themarshallislands", Country::the_marshall_islands()),
+    ("thenetherlands", Country::the_netherlands()),
+    ("theniger", Country::the_niger()),
+    (
+        "thenorthernmarianaislands",
+        Country::the_northern_mariana_islands(),
+    ),
+    ("thephilippines", Country::the_philippines()),
+    ("therepublicofkorea", Country::the_republic_of_korea()),
+    ("therepublicofmoldova", Country::the_republic_of_moldova()),
+    ("therussianfederation", Country::the_russian_federation()),
+    ("thesudan", Country::the_sudan()),
+    (
+        "theturksandcaicosislands",
+        Country::the_turks_and_caicos_islands(),
+    ),
+    ("theunitedarabemirates", Country::the_united_arab_emirates()),
+    (
+        "theunitedkingdomofgreatbritainandnorthernireland",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    (
+        "theunitedstatesminoroutlyingislands",
+        Country::the_united_states_minor_outlying_islands(),
+    ),
+    (
+        "theunitedstatesofamerica",
+        Country::the_united_states_of_america(),
+    ),
+    ("timorese", Country::timor_leste()),
+    ("timorleste", Country::timor_leste()),
+    ("tobago", Country::trinidad_and_tobago()),
+    ("togo", Country::togo()),
+    ("togolese", Country::togo()),
+    ("tokelau", Country::tokelau()),
+    ("tonga", Country::tonga()),
+    ("tongan", Country::tonga()),
+    ("trinidad", Country::trinidad_and_tobago()),
+    ("trinidadandtobago", Country::trinidad_and_tobago()),
+    ("trinidadian", Country::trinidad_and_tobago()),
+    ("tunisia", Country::tunisia()),
+    ("tunisian", Country::tunisia()),
+    ("turkey", Country::turkiye()),
+    ("turkish", Country::turkiye()),
+    ("turkiye", Country::turkiye()),
+    ("turkmen", Country::turkmenistan()),
+    ("turkmenistan", Country::turkmenistan()),
+    (
+        "turksandcaicosislands",
+        Country::the_turks_and_caicos_islands(),
+    ),
+    ("tuvalu", Country::tuvalu()),
+    ("tuvaluan", Country::tuvalu()),
+    ("uganda", Country::uganda()),
+    ("ugandan", Country::uganda()),
+    ("ukraine", Country::ukraine()),
+    ("ukrainian", Country::ukraine()),
+    ("unitedarabemirates", Country::the_united_arab_emirates()),
+    (
+        "unitedkingdom",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    (
+        "unitedkingdomofgreatbritain",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    (
+        "unitedkingdomofgreatbritainandnorthernireland",
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+    ),
+    (
+        "unitedrepublicoftanzania",
+        Country::united_republic_of_tanzania(),
+    ),
+    ("unitedstates", Country::the_united_states_of_america()),
+    (
+        "unitedstatesminoroutlyingislands",
+        Country::the_united_states_minor_outlying_islands(),
+    ),
+    (
+        "unitedstatesofamerica",
+        Country::the_united_states_of_america(),
+    ),
+    ("uruguay", Country::uruguay()),
+    ("uruguayan", Country::uruguay()),
+    ("usvirginislands", Country::us_virgin_islands()),
+    ("uzbek", Country::uzbekistan()),
+    ("uzbekistan", Country::uzbekistan()),
+    ("vanuatu", Country::vanuatu()),
+    ("vanuatuan", Country::vanuatu()),
+    ("vatican", Country::the_holy_see()),
+    ("vaticancity", Country::the_holy_see()),
+    ("venezuela", Country::bolivarian_republic_of_venezuela()),
+    ("venezuelan", Country::bolivarian_republic_of_venezuela()),
+    ("vietnam", Country::vietnam()),
+    ("vietnamese", Country::vietnam()),
+    ("wallisandfutuna", Country::wallis_and_futuna()),
+    ("westernsahara", Country::western_sahara()),
+    ("yemen", Country::yemen()),
+    ("yemeni", Country::yemen()),
+    ("zambia", Country::zambia()),
+    ("zambian", Country::zambia()),
+    ("zimbabwe", Country::zimbabwe()),
+    ("zimbabwean", Country::zimbabwe()),
+];
+
+const _: () = assert!(is_sorted(PHRASES), "PHRASES must be sorted");
+
+/// Find every country mentioned in `text` by name, alias, demonym or a common
+/// abbreviation like `UK`. Use [`Mentions::codes`] to also match alpha2 and alpha3 codes.
+#[must_use]
+pub fn find_countries(text: &str) -> Mentions<'_> {
+    Mentions {
+        text,
+        pos: 0,
+        codes: false,
+        capitalized: false,
+        #[cfg(feature = "std")]
+        overrides: crate::overrides::installed(),
+    }
+}
+
+/// An iterator over the countries mentioned in some text.
+///
+/// Each item is the country, the byte range of the mention and the text that matched.
+#[derive(Clone, Debug)]
+pub struct Mentions<'a> {
+    text: &'a str,
+    pos: usize,
+    codes: bool,
+    capitalized: bool,
+    /// The overrides installed when the search started
+    #[cfg(feature = "std")]
+    overrides: Vec<Country>,
+}
+
+impl Mentions<'_> {
+    /// Also match uppercase alpha2 and alpha3 codes like `DE` or `FRA`.
+    /// This is off by default since short uppercase words like `IT` or `CAN`
+    /// are often not countries.
+    ///
+    /// ```
+    /// use celes::{Country, text::find_countries};
+    ///
+    /// assert_eq!(0, find_countries("Ships to DE and FRA").count());
+    /// let mentions: Vec<_> = find_countries("Ships to DE and FRA").codes(true).collect();
+    /// assert_eq!(Country::germany(), mentions[0].0);
+    /// assert_eq!(Country::france(), mentions[1].0);
+    /// ```
+    #[must_use]
+    pub const fn codes(mut self, codes: bool) -> Self {
+        self.codes = codes;
+        self
+    }
+
+    /// Only match mentions starting with an uppercase letter.
+    /// This avoids matching common words like `turkey` or `china` in lowercase text.
+    ///
+    /// ```
+    /// use celes::{Country, text::find_countries};
+    ///
+    /// let text = "a turkey sandwich in Turkey";
+    /// assert_eq!(2, find_countries(text).count());
+    /// let mentions: Vec<_> = find_countries(text).capitalized(true).collect();
+    /// assert_eq!(1, mentions.len());
+    /// assert_eq!(Country::turkiye(), mentions[0].0);
+    /// assert_eq!("Turkey", mentions[0].2);
+    /// ```
+    #[must_use]
+    pub const fn capitalized(mut self, capitalized: bool) -> Self {
+        self.capitalized = capitalized;
+        self
+    }
+
+    /// The byte range of the next word at or after `from`
+    fn word(&self, from: usize) -> Option<Range<usize>> {
+        let rest = &self.text[from..];
+        let start = from + rest.find(char::is_alphanumeric)?;
+        let end = self.text[start..]
+            .find(|c: char| !c.is_alphanumeric())
+            .map_or(self.text.len(), |i| start + i);
+        Some(start..end)
+    }
+
+    /// The longest phrase starting with `first` and where it ends. Each word
+    /// narrows the phrases that can still match until none or only exact matches remain.
+    fn longest(&self, first: Range<usize>) -> Option<(Country, usize)> {
+        let mut buf = [0u8; 64];
+        let mut len = 0;
+        let mut phrases = PHRASES;
+        let mut found = None;
+        let mut word = first;
+        loop {
+            for c in self.text[word.clone()].chars().flat_map(char::to_lowercase) {
+                let c = fold(c);
+                if len + c.len_utf8() > buf.len() {
+                    return found;
+                }
+                len += c.encode_utf8(&mut buf[len..]).len();
+            }
+            // SAFETY: only whole UTF-8 encoded chars are written to the buffer
+            let key = unsafe { core::str::from_utf8_unchecked(&buf[..len]) };
+            phrases = &phrases[phrases.partition_point(|(p, _)| *p < key)..];
+            phrases = &phrases[..phrases.partition_point(|(p, _)| p.starts_with(key))];
+            match phrases {
+                [] => return found,
+                [(p, country), rest @ ..] if *p == key => {
+                    found = Some((*country, word.end));
+                    if rest.is_empty() {
+                        return found;
+                    }
+                }
+                _ => {}
+            }
+            let Some(next) = self.word(word.end) else {
+                return found;
+            };
+            let gap = &self.text[word.end..next.start];
+            if gap.chars().count() > 3 || !gap.chars().all(is_joiner) {
+                return found;
+            }
+            word = next;
+        }
+    }
+
+    fn lookup_code(&self, word: &str) -> Option<Country> {
+        let mut buf = [0u8; 3];
+        if word.len() <= buf.len() && word.is_ascii() {
+            let upper = &mut buf[..word.len()];
+            upper.copy_from_slice(word.as_bytes());
+            upper.make_ascii_uppercase();
+            // SAFETY: input was ASCII, ASCII uppercase is still valid UTF-8
+            let upper = unsafe { core::str::from_utf8_unchecked(upper) };
+            if let Some(country) = ABBREVIATIONS.get(upper) {
+                return Some(*country);
+            }
+        }
+        if !self.codes || !word.bytes().all(|b| b.is_ascii_uppercase()) {
+            return None;
+        }
+        let is_code = |c: &Country| c.alpha2() == word || c.alpha3() == word;
+        let country = match word.len() {
+            2 => Country::from_alpha2_const(word),
+            3 => Country::from_alpha3_const(word),
+            _ => None,
+        }
+        .map(|c| self.current(c))
+        // Codes an override replaced no longer match
+        .filter(is_code);
+        #[cfg(feature = "std")]
+        let country = country.or_else(|| self.overrides.iter().copied().find(is_code));
+        country
+    }
+
+    /// The installed override replacing `country`, or `country` itself
+    #[cfg_attr(not(feature = "std"), allow(clippy::unused_self))]
+    fn current(&self, country: Country) -> Country {
+        #[cfg(feature = "std")]
+        if let Some(replaced) = self.overrides.iter().find(|o| o.value() == country.value()) {
+            return *replaced;
+        }
+        country
+    }
+}
+
+impl<'a> Iterator for Mentions<'a> {
+    type Item = (Country, Range<usize>, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(first) = self.word(self.pos) {
+            self.pos = first.end;
+            if self.capitalized
+                && !self.text[first.clone()]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_uppercase)
+            {
+                continue;
+            }
+            let found = self.longest(first.clone()).or_else(|| {
+                self.lookup_code(&self.text[first.clone()])
+                    .map(|c| (c, first.end))
+            });
+            if let Some((country, end)) = found {
+                let range = first.start..end;
+                self.pos = end;
+                return Some((self.current(country), range.clone(), &self.text[range]));
+            }
+        }
+        self.pos = self.text.len();
+        None
+    }
+}
+
+impl FusedIterator for Mentions<'_> {}
+
+/// Characters that may join the words of a single mention
+const fn is_joiner(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '\'' | '’' | '.')
+}
+
+/// Strip the accent from common Latin letters so "Côte" matches "cote"
+const fn fold(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        _ => c,
+    }
+}
+
+/// True if `phrases` are sorted by their keys. Checked when compiling.
+const fn is_sorted(phrases: &[(&str, Country)]) -> bool {
+    let mut i = 1;
+    while i < phrases.len() {
+        let (a, b) = (phrases[i - 1].0.as_bytes(), phrases[i].0.as_bytes());
+        let mut j = 0;
+        while j < a.len() && j < b.len() && a[j] == b[j] {
+            j += 1;
+        }
+        let less = if j < a.len() && j < b.len() {
+            a[j] < b[j]
+        } else {
+            a.len() < b.len()
+        };
+        if !less {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PHRASES, fold};
+    use crate::Country;
+
+    // The phrases copy the private name and alias maps, so check they haven't drifted apart
+    #[test]
+    fn phrases_cover_names_and_aliases() {
+        let names = Country::from_name_table().entries().map(|e| (e, true));
+        let aliases = Country::from_alias_table().entries().map(|e| (e, false));
+        for ((key, country), is_name) in names.chain(aliases) {
+            let folded: String = key.chars().map(fold).collect();
+            if !folded.chars().all(|c| c.is_ascii_alphanumeric()) {
+                continue;
+            }
+            let index = PHRASES
+                .binary_search_by(|(p, _)| (*p).cmp(folded.as_str()))
+                .unwrap_or_else(|_| panic!("{key} is missing"));
+            if is_name {
+                assert_eq!(*country, PHRASES[index].1, "{key}");
+            }
+        }
+    }
+}
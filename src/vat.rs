@@ -0,0 +1,649 @@
+/*
+    Copyright Michael Lodder. All Rights Reserved.
+    SPDX-License-Identifier: Apache-2.0 OR MIT
+*/
+//! EU VAT identification numbers
+//!
+//! VAT numbers start with the prefix used by the VAT Information Exchange System (VIES).
+//! The prefix is the alpha2 code of the member state except for Greece which uses `EL`
+//! and Northern Ireland which uses `XI`. Northern Ireland isn't a separate ISO 3166-1
+//! country so `XI` numbers resolve to the United Kingdom, but the United Kingdom
+//! itself has no prefix since Great Britain is outside the EU VAT area.
+//!
+//! Validation is done offline and only checks the syntax and check digits.
+//! It cannot tell whether a number has actually been issued.
+//!
+//! ```
+//! use celes::{Country, vat};
+//!
+//! let (country, number) = vat::validate("EL 094259216").unwrap();
+//! assert_eq!(Country::greece(), country);
+//! assert_eq!("EL094259216", number.as_str());
+//! assert_eq!("094259216", number.number());
+//! ```
+
+use crate::{Country, lookup_ascii_lowercase};
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use phf::{Map, phf_map};
+
+/// The maximum length of a VAT number including the prefix
+const MAX_VAT_NUMBER_LEN: usize = 16;
+
+/// The errors that can occur when validating a VAT number
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VatError {
+    /// The prefix is not used by any EU member state
+    UnknownPrefix,
+    /// The number does not match the syntax used by the member state
+    InvalidFormat,
+    /// The check digits are wrong
+    InvalidChecksum,
+}
+
+impl Display for VatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::UnknownPrefix => write!(f, "unknown prefix"),
+            Self::InvalidFormat => write!(f, "invalid format"),
+            Self::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+impl core::error::Error for VatError {}
+
+/// A validated VAT number stored inline without heap allocation
+///
+/// The number is stored upper case without any spaces, dots or hyphens.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VatNumber {
+    buf: [u8; MAX_VAT_NUMBER_LEN],
+    len: usize,
+}
+
+impl VatNumber {
+    /// The VAT number including the prefix
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only ASCII bytes are ever written to the buffer
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// The VIES prefix
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.as_str()[..2]
+    }
+
+    /// The number without the prefix
+    #[must_use]
+    pub fn number(&self) -> &str {
+        &self.as_str()[2..]
+    }
+}
+
+impl AsRef<str> for VatNumber {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for VatNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "VatNumber({})", self.as_str())
+    }
+}
+
+impl Display for VatNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Validate the syntax and check digits of a VAT number including its prefix
+///
+/// Spaces, dots and hyphens are ignored and the input is case-insensitive.
+///
+/// # Errors
+///
+/// Returns a [`VatError`] describing why the number is invalid.
+///
+/// ```
+/// use celes::{Country, vat::{self, VatError}};
+///
+/// let (country, _) = vat::validate("DE136695976").unwrap();
+/// assert_eq!(Country::germany(), country);
+///
+/// let (country, _) = vat::validate("XI 980 7806 84").unwrap();
+/// assert_eq!(Country::the_united_kingdom_of_great_britain_and_northern_ireland(), country);
+///
+/// assert_eq!(Err(VatError::InvalidChecksum), vat::validate("DE136695977"));
+/// assert_eq!(Err(VatError::InvalidFormat), vat::validate("DE13669597"));
+/// assert_eq!(Err(VatError::UnknownPrefix), vat::validate("GR094259216"));
+/// ```
+pub fn validate(vat: &str) -> Result<(Country, VatNumber), VatError> {
+    let mut out = VatNumber {
+        buf: [0u8; MAX_VAT_NUMBER_LEN],
+        len: 0,
+    };
+    for b in vat.bytes().filter(|b| !matches!(b, b' ' | b'.' | b'-')) {
+        if !b.is_ascii_alphanumeric() && !matches!(b, b'+' | b'*') {
+            return Err(VatError::InvalidFormat);
+        }
+        if out.len == MAX_VAT_NUMBER_LEN {
+            return Err(VatError::InvalidFormat);
+        }
+        out.buf[out.len] = b.to_ascii_uppercase();
+        out.len += 1;
+    }
+    if out.len < 2 {
+        return Err(VatError::UnknownPrefix);
+    }
+    let country = Country::from_vat_prefix(out.prefix()).map_err(|_| VatError::UnknownPrefix)?;
+    check(out.prefix(), out.number().as_bytes())?;
+    Ok((country, out))
+}
+
+/// VIES prefixes lower cased for lookups
+static VAT_PREFIXES: Map<&'static str, Country> = phf_map! {
+    "at" => Country::austria(),
+    "be" => Country::belgium(),
+    "bg" => Country::bulgaria(),
+    "cy" => Country::cyprus(),
+    "cz" => Country::czechia(),
+    "de" => Country::germany(),
+    "dk" => Country::denmark(),
+    "ee" => Country::estonia(),
+    "el" => Country::greece(),
+    "es" => Country::spain(),
+    "fi" => Country::finland(),
+    "fr" => Country::france(),
+    "hr" => Country::croatia(),
+    "hu" => Country::hungary(),
+    "ie" => Country::ireland(),
+    "it" => Country::italy(),
+    "lt" => Country::lithuania(),
+    "lu" => Country::luxembourg(),
+    "lv" => Country::latvia(),
+    "mt" => Country::malta(),
+    "nl" => Country::the_netherlands(),
+    "pl" => Country::poland(),
+    "pt" => Country::portugal(),
+    "ro" => Country::romania(),
+    "se" => Country::sweden(),
+    "si" => Country::slovenia(),
+    "sk" => Country::slovakia(),
+    "xi" => Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+};
+
+impl Country {
+    /// The VIES prefix used for VAT numbers or `None` if the country is not part of the EU VAT area.
+    /// The United Kingdom returns `None` since only Northern Ireland uses the `XI` prefix.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Some("DE"), Country::germany().vat_prefix());
+    /// assert_eq!(Some("EL"), Country::greece().vat_prefix());
+    /// assert_eq!(None, Country::the_united_kingdom_of_great_britain_and_northern_ireland().vat_prefix());
+    /// assert_eq!(None, Country::switzerland().vat_prefix());
+    /// ```
+    #[must_use]
+    pub fn vat_prefix(&self) -> Option<&'static str> {
+        match self.value() {
+            300 => Some("EL"),
+            40 | 56 | 100 | 191 | 196 | 203 | 208 | 233 | 246 | 250 | 276 | 348 | 372 | 380
+            | 428 | 440 | 442 | 470 | 528 | 616 | 620 | 642 | 703 | 705 | 724 | 752 => {
+                Some(self.alpha2())
+            }
+            _ => None,
+        }
+    }
+
+    /// Given the VIES prefix of a VAT number, return a country or an error if
+    /// the parameter doesn't match any EU member state. This is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prefix is not used by VIES.
+    ///
+    /// ```
+    /// use celes::Country;
+    ///
+    /// assert_eq!(Country::greece(), Country::from_vat_prefix("EL").unwrap());
+    /// assert_eq!(Country::germany(), Country::from_vat_prefix("de").unwrap());
+    /// assert!(Country::from_vat_prefix("GR").is_err());
+    /// assert!(Country::from_vat_prefix("CH").is_err());
+    /// ```
+    pub fn from_vat_prefix<A: AsRef<str>>(prefix: A) -> Result<Self, &'static str> {
+        lookup_ascii_lowercase(&VAT_PREFIXES, prefix.as_ref())
+            .copied()
+            .ok_or("invalid vat prefix")
+    }
+}
+
+fn check(prefix: &str, n: &[u8]) -> Result<(), VatError> {
+    let valid = match prefix {
+        "AT" => check_at(n)?,
+        "BE" => check_be(n)?,
+        "BG" => check_bg(n)?,
+        "CY" => check_cy(n)?,
+        "CZ" => check_cz(n)?,
+        "DE" => digits(n, 9)? && n[0] != b'0' && mod_11_10(n),
+        "DK" => {
+            digits(n, 8)?
+                && n[0] != b'0'
+                && weighted(n, &[2, 7, 6, 5, 4, 3, 2, 1]).is_multiple_of(11)
+        }
+        "EE" => check_ee(n)?,
+        "EL" => check_el(n)?,
+        "ES" => check_es(n)?,
+        "FI" => check_fi(n)?,
+        "FR" => check_fr(n)?,
+        "HR" => digits(n, 11)? && mod_11_10(n),
+        "HU" => check_hu(n)?,
+        "IE" => check_ie(n)?,
+        "IT" => digits(n, 11)? && luhn(n),
+        "LT" => check_lt(n)?,
+        "LU" => digits(n, 8)? && number(&n[..6]) % 89 == number(&n[6..]),
+        "LV" => check_lv(n)?,
+        "MT" => check_mt(n)?,
+        "NL" => check_nl(n)?,
+        "PL" => check_pl(n)?,
+        "PT" => check_pt(n)?,
+        "RO" => check_ro(n)?,
+        "SE" => digits(n, 12)? && &n[10..] == b"01" && luhn(&n[..10]),
+        "SI" => check_si(n)?,
+        "SK" => check_sk(n)?,
+        "XI" => check_xi(n)?,
+        _ => return Err(VatError::UnknownPrefix),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(VatError::InvalidChecksum)
+    }
+}
+
+/// True if `n` has `len` digits, otherwise an invalid format error
+fn digits(n: &[u8], len: usize) -> Result<bool, VatError> {
+    if n.len() == len && n.iter().all(u8::is_ascii_digit) {
+        Ok(true)
+    } else {
+        Err(VatError::InvalidFormat)
+    }
+}
+
+fn digit(b: u8) -> u64 {
+    u64::from(b - b'0')
+}
+
+fn number(n: &[u8]) -> u64 {
+    n.iter().fold(0, |acc, b| acc * 10 + digit(*b))
+}
+
+fn weighted(n: &[u8], weights: &[u64]) -> u64 {
+    n.iter().zip(weights).map(|(b, w)| digit(*b) * w).sum()
+}
+
+fn luhn(n: &[u8]) -> bool {
+    let sum: u64 = n
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let d = digit(*b);
+            if i % 2 == 1 {
+                let d = d * 2;
+                if d > 9 { d - 9 } else { d }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// ISO 7064 MOD 11,10 where the last digit is the check digit
+fn mod_11_10(n: &[u8]) -> bool {
+    let (body, check) = n.split_at(n.len() - 1);
+    let product = body.iter().fold(10, |product, b| {
+        let sum = (digit(*b) + product) % 10;
+        let sum = if sum == 0 { 10 } else { sum };
+        (2 * sum) % 11
+    });
+    (11 - product) % 10 == digit(check[0])
+}
+
+fn check_at(n: &[u8]) -> Result<bool, VatError> {
+    if n.first() != Some(&b'U') {
+        return Err(VatError::InvalidFormat);
+    }
+    let n = &n[1..];
+    digits(n, 8)?;
+    let sum: u64 = n[..7]
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let d = digit(*b);
+            if i % 2 == 1 {
+                (d * 2) / 10 + (d * 2) % 10
+            } else {
+                d
+            }
+        })
+        .sum();
+    Ok((10 - (sum + 4) % 10) % 10 == digit(n[7]))
+}
+
+fn check_be(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 10)?;
+    if n[0] > b'1' {
+        return Err(VatError::InvalidFormat);
+    }
+    Ok(97 - number(&n[..8]) % 97 == number(&n[8..]))
+}
+
+fn check_bg(n: &[u8]) -> Result<bool, VatError> {
+    if n.len() == 9 {
+        digits(n, 9)?;
+        let mut r = weighted(n, &[1, 2, 3, 4, 5, 6, 7, 8]) % 11;
+        if r == 10 {
+            r = weighted(n, &[3, 4, 5, 6, 7, 8, 9, 10]) % 11 % 10;
+        }
+        return Ok(r == digit(n[8]));
+    }
+    digits(n, 10)?;
+    let check = digit(n[9]);
+    let person = weighted(n, &[2, 4, 8, 5, 10, 9, 7, 3, 6]) % 11 % 10;
+    let foreigner = weighted(n, &[21, 19, 17, 13, 11, 9, 7, 3, 1]) % 10;
+    let other = 11 - weighted(n, &[4, 3, 2, 7, 6, 5, 4, 3, 2]) % 11;
+    Ok(person == check || foreigner == check || (other % 11 == check && other != 10))
+}
+
+fn check_cy(n: &[u8]) -> Result<bool, VatError> {
+    const ODD: [u64; 10] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21];
+    if n.len() != 9 || !n[..8].iter().all(u8::is_ascii_digit) || !n[8].is_ascii_uppercase() {
+        return Err(VatError::InvalidFormat);
+    }
+    if !matches!(n[0], b'0' | b'1' | b'3' | b'4' | b'5' | b'9') {
+        return Err(VatError::InvalidFormat);
+    }
+    let sum: u64 = n[..8]
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let d = digit(*b);
+            if i % 2 == 0 {
+                ODD[usize::from(*b - b'0')]
+            } else {
+                d
+            }
+        })
+        .sum();
+    Ok(u64::from(n[8] - b'A') == sum % 26)
+}
+
+fn check_cz(n: &[u8]) -> Result<bool, VatError> {
+    if !(8..=10).contains(&n.len()) || !n.iter().all(u8::is_ascii_digit) {
+        return Err(VatError::InvalidFormat);
+    }
+    match n.len() {
+        8 => {
+            if n[0] == b'9' {
+                return Err(VatError::InvalidFormat);
+            }
+            let c = 11 - weighted(n, &[8, 7, 6, 5, 4, 3, 2]) % 11;
+            Ok(c % 10 == digit(n[7]))
+        }
+        10 => Ok(number(n).is_multiple_of(11)),
+        // Nine digit numbers for individuals only have a syntax check
+        _ => Ok(true),
+    }
+}
+
+fn check_ee(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 9)?;
+    if &n[..2] != b"10" {
+        return Err(VatError::InvalidFormat);
+    }
+    let c = (10 - weighted(n, &[3, 7, 1, 3, 7, 1, 3, 7]) % 10) % 10;
+    Ok(c == digit(n[8]))
+}
+
+fn check_el(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 9)?;
+    let c = weighted(n, &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10;
+    Ok(c == digit(n[8]))
+}
+
+fn check_es(n: &[u8]) -> Result<bool, VatError> {
+    const DNI: &[u8; 23] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+    if n.len() != 9 || !n[1..8].iter().all(u8::is_ascii_digit) {
+        return Err(VatError::InvalidFormat);
+    }
+    let (first, body, check) = (n[0], &n[1..8], n[8]);
+    match first {
+        b'0'..=b'9' => {
+            if !check.is_ascii_uppercase() {
+                return Err(VatError::InvalidFormat);
+            }
+            let index = number(&n[..8]) % 23;
+            Ok(DNI[usize::try_from(index).unwrap_or_default()] == check)
+        }
+        b'X' | b'Y' | b'Z' | b'K' | b'L' | b'M' => {
+            if !check.is_ascii_uppercase() {
+                return Err(VatError::InvalidFormat);
+            }
+            let prefix = match first {
+                b'Y' => 10_000_000,
+                b'Z' => 20_000_000,
+                _ => 0,
+            };
+            let index = (prefix + number(body)) % 23;
+            Ok(DNI[usize::try_from(index).unwrap_or_default()] == check)
+        }
+        b'A'..=b'H' | b'J' | b'N' | b'P'..=b'S' | b'U'..=b'W' => {
+            let sum: u64 = body
+                .iter()
+                .enumerate()
+                .map(|(i, b)| {
+                    let d = digit(*b);
+                    if i % 2 == 0 {
+                        (d * 2) / 10 + (d * 2) % 10
+                    } else {
+                        d
+                    }
+                })
+                .sum();
+            let c = (10 - sum % 10) % 10;
+            Ok(
+                check == b"JABCDEFGHI"[usize::try_from(c).unwrap_or_default()]
+                    || (check.is_ascii_digit() && digit(check) == c),
+            )
+        }
+        _ => Err(VatError::InvalidFormat),
+    }
+}
+
+fn check_fi(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 8)?;
+    let c = 11 - weighted(n, &[7, 9, 10, 5, 8, 4, 2]) % 11;
+    Ok(c != 10 && c % 11 == digit(n[7]))
+}
+
+fn check_fr(n: &[u8]) -> Result<bool, VatError> {
+    if n.len() != 11 || !n[2..].iter().all(u8::is_ascii_digit) {
+        return Err(VatError::InvalidFormat);
+    }
+    let key = &n[..2];
+    if key.iter().all(u8::is_ascii_digit) {
+        return Ok((12 + 3 * (number(&n[2..]) % 97)) % 97 == number(key));
+    }
+    // Alphanumeric keys are assigned without a published algorithm
+    if key
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() && *b != b'I' && *b != b'O')
+    {
+        Ok(true)
+    } else {
+        Err(VatError::InvalidFormat)
+    }
+}
+
+fn check_hu(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 8)?;
+    let c = (10 - weighted(n, &[9, 7, 3, 1, 9, 7, 3]) % 10) % 10;
+    Ok(c == digit(n[7]))
+}
+
+fn check_ie(n: &[u8]) -> Result<bool, VatError> {
+    const CHECK: &[u8; 23] = b"WABCDEFGHIJKLMNOPQRSTUV";
+    let mut body = [0u8; 7];
+    let (check, extra) = match n {
+        // Old style numbers like 8D79739I are rearranged to the new style
+        [d, b'A'..=b'Z' | b'+' | b'*', rest @ .., check]
+            if n.len() == 8 && d.is_ascii_digit() && rest.iter().all(u8::is_ascii_digit) =>
+        {
+            body[0] = b'0';
+            body[1..6].copy_from_slice(rest);
+            body[6] = *d;
+            (*check, None)
+        }
+        [rest @ .., check] if n.len() == 8 && rest.iter().all(u8::is_ascii_digit) => {
+            body.copy_from_slice(rest);
+            (*check, None)
+        }
+        [rest @ .., check, extra] if n.len() == 9 && rest.iter().all(u8::is_ascii_digit) => {
+            body.copy_from_slice(rest);
+            (*check, Some(*extra))
+        }
+        _ => return Err(VatError::InvalidFormat),
+    };
+    if !check.is_ascii_uppercase() || extra.is_some_and(|e| !matches!(e, b'A'..=b'I' | b'W')) {
+        return Err(VatError::InvalidFormat);
+    }
+    let mut sum = weighted(&body, &[8, 7, 6, 5, 4, 3, 2]);
+    if let Some(e @ b'A'..=b'I') = extra {
+        sum += 9 * u64::from(e - b'A' + 1);
+    }
+    Ok(CHECK[usize::try_from(sum % 23).unwrap_or_default()] == check)
+}
+
+fn check_lt(n: &[u8]) -> Result<bool, VatError> {
+    let len = n.len();
+    if !(len == 9 || len == 12) || !n.iter().all(u8::is_ascii_digit) || n[len - 2] != b'1' {
+        return Err(VatError::InvalidFormat);
+    }
+    let body = &n[..len - 1];
+    let weighted_by = |offset: usize| -> u64 {
+        body.iter()
+            .enumerate()
+            .map(|(i, b)| digit(*b) * (1 + (i + offset) % 9) as u64)
+            .sum()
+    };
+    let mut r = weighted_by(0) % 11;
+    if r == 10 {
+        r = weighted_by(2) % 11 % 10;
+    }
+    Ok(r == digit(n[len - 1]))
+}
+
+fn check_lv(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 11)?;
+    if n[0] > b'3' {
+        Ok(weighted(n, &[9, 1, 4, 8, 3, 10, 2, 5, 7, 6, 1]) % 11 == 3)
+    } else {
+        let c = (1 + weighted(n, &[10, 5, 8, 4, 2, 1, 6, 3, 7, 9])) % 11 % 10;
+        Ok(c == digit(n[10]))
+    }
+}
+
+fn check_mt(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 8)?;
+    if n[0] == b'0' {
+        return Err(VatError::InvalidFormat);
+    }
+    let c = 37 - weighted(n, &[3, 4, 6, 7, 8, 9]) % 37;
+    Ok(c == number(&n[6..]))
+}
+
+fn check_nl(n: &[u8]) -> Result<bool, VatError> {
+    if n.len() != 12 || n[9] != b'B' {
+        return Err(VatError::InvalidFormat);
+    }
+    digits(&n[..9], 9)?;
+    digits(&n[10..], 2)?;
+    let r = weighted(n, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+    if r != 10 && r == digit(n[8]) {
+        return Ok(true);
+    }
+    // Numbers issued to sole proprietors since 2020 use ISO 7064 MOD 97-10 over "NL" + number
+    let r = b"NL".iter().chain(n).fold(0u64, |acc, b| {
+        if b.is_ascii_digit() {
+            (acc * 10 + digit(*b)) % 97
+        } else {
+            (acc * 100 + u64::from(b - b'A') + 10) % 97
+        }
+    });
+    Ok(r == 1)
+}
+
+fn check_pl(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 10)?;
+    let r = weighted(n, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11;
+    Ok(r != 10 && r == digit(n[9]))
+}
+
+fn check_pt(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 9)?;
+    if n[0] == b'0' {
+        return Err(VatError::InvalidFormat);
+    }
+    let c = 11 - weighted(n, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+    Ok(if c > 9 { 0 } else { c } == digit(n[8]))
+}
+
+fn check_ro(n: &[u8]) -> Result<bool, VatError> {
+    if !(2..=10).contains(&n.len()) || !n.iter().all(u8::is_ascii_digit) || n[0] == b'0' {
+        return Err(VatError::InvalidFormat);
+    }
+    let mut padded = [b'0'; 10];
+    padded[10 - n.len()..].copy_from_slice(n);
+    let c = weighted(&padded, &[7, 5, 3, 2, 1, 7, 5, 3, 2]) * 10 % 11 % 10;
+    Ok(c == digit(padded[9]))
+}
+
+fn check_si(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 8)?;
+    if n[0] == b'0' {
+        return Err(VatError::InvalidFormat);
+    }
+    let c = 11 - weighted(n, &[8, 7, 6, 5, 4, 3, 2]) % 11;
+    Ok(c != 11 && c % 10 == digit(n[7]))
+}
+
+fn check_sk(n: &[u8]) -> Result<bool, VatError> {
+    digits(n, 10)?;
+    if n[0] == b'0' || !matches!(n[2], b'2' | b'3' | b'4' | b'7' | b'8' | b'9') {
+        return Err(VatError::InvalidFormat);
+    }
+    Ok(number(n).is_multiple_of(11))
+}
+
+fn check_xi(n: &[u8]) -> Result<bool, VatError> {
+    match n {
+        // Government departments and health authorities
+        [b'G', b'D', rest @ ..] => Ok(digits(rest, 3)? && number(rest) < 500),
+        [b'H', b'A', rest @ ..] => Ok(digits(rest, 3)? && number(rest) >= 500),
+        _ => {
+            if !(n.len() == 9 || n.len() == 12) {
+                return Err(VatError::InvalidFormat);
+            }
+            digits(n, n.len())?;
+            // The last three digits of twelve digit numbers identify a branch
+            let sum = weighted(n, &[8, 7, 6, 5, 4, 3, 2]) + number(&n[7..9]);
+            Ok(sum.is_multiple_of(97) || (sum + 55).is_multiple_of(97))
+        }
+    }
+}
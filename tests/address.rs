@@ -0,0 +1,86 @@
+//! Address format tests
+use celes::{Address, AddressField, Country};
+
+#[test]
+fn templates_are_consistent() {
+    for c in &Country::get_countries() {
+        let format = c.address_format();
+        for code in format
+            .template
+            .split('%')
+            .skip(1)
+            .filter_map(|t| t.chars().next())
+        {
+            assert!(
+                code == 'n' || AddressField::from_code(code).is_some(),
+                "{} - unknown code {code}",
+                c.alpha2()
+            );
+        }
+        for code in format.required.chars().chain(format.uppercase.chars()) {
+            let field = AddressField::from_code(code);
+            assert!(field.is_some(), "{} - unknown code {code}", c.alpha2());
+        }
+        for code in format.required.chars() {
+            let field = AddressField::from_code(code).expect("valid code");
+            assert!(
+                format.fields().any(|f| f == field),
+                "{} - {field:?}",
+                c.alpha2()
+            );
+        }
+    }
+}
+
+#[test]
+fn format_address() {
+    let address = Address {
+        name: "Jean Dupont",
+        street_address: "8 Rue de Rivoli",
+        locality: "Paris",
+        postal_code: "75004",
+        sorting_code: "Cedex 04",
+        ..Address::default()
+    };
+    assert_eq!(
+        Country::france().format_address(&address).to_string(),
+        "Jean Dupont\n8 Rue de Rivoli\n75004 PARIS CEDEX 04"
+    );
+
+    let address = Address {
+        name: "John Smith",
+        street_address: "10 Downing Street",
+        locality: "London",
+        postal_code: "SW1A 2AA",
+        ..Address::default()
+    };
+    assert_eq!(
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland()
+            .format_address(&address)
+            .to_string(),
+        "John Smith\n10 Downing Street\nLONDON\nSW1A 2AA"
+    );
+
+    let address = Address {
+        name: "Jane Roe",
+        street_address: "1 Smith Street",
+        locality: "St Peter Port",
+        postal_code: "GY1 2AB",
+        ..Address::default()
+    };
+    assert_eq!(
+        Country::guernsey().format_address(&address).to_string(),
+        "Jane Roe\n1 Smith Street\nST PETER PORT\nGUERNSEY\nGY1 2AB"
+    );
+
+    let address = Address {
+        name: "Erika Mustermann",
+        street_address: "Musterweg 1",
+        locality: "Zürich",
+        ..Address::default()
+    };
+    assert_eq!(
+        Country::switzerland().format_address(&address).to_string(),
+        "Erika Mustermann\nMusterweg 1\nZürich"
+    );
+}
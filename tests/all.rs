@@ -0,0 +1,36 @@
+//! Static country slice tests
+use celes::Country;
+
+#[test]
+fn all_matches_get_countries() {
+    assert_eq!(Country::COUNT, Country::all().len());
+    assert_eq!(&Country::get_countries()[..], Country::all());
+}
+
+#[test]
+fn ordered_iterators() {
+    let alpha2: Vec<_> = Country::all_by_alpha2().map(|c| c.alpha2()).collect();
+    let alpha3: Vec<_> = Country::all_by_alpha3().map(|c| c.alpha3()).collect();
+    let numeric: Vec<_> = Country::all_by_numeric().map(|c| c.value()).collect();
+    for codes in [&alpha2, &alpha3] {
+        assert_eq!(Country::COUNT, codes.len());
+        assert!(codes.windows(2).all(|w| w[0] < w[1]));
+    }
+    assert_eq!(Country::COUNT, numeric.len());
+    assert!(numeric.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn dense_index() {
+    for (i, country) in Country::all().iter().enumerate() {
+        assert_eq!(i, country.index());
+        assert_eq!(Some(i), country.checked_index());
+    }
+    #[cfg(feature = "std")]
+    {
+        let territory = Country::user_assigned("900", 900, "XA", "XAA", "Atlantis")
+            .expect("valid user-assigned code");
+        assert!(territory.index() >= Country::COUNT);
+        assert!(territory.checked_index().is_none());
+    }
+}
@@ -0,0 +1,42 @@
+//! Byte slice lookup tests
+use celes::Country;
+
+#[test]
+fn matches_str_lookups() {
+    for country in Country::get_countries() {
+        assert_eq!(
+            Ok(country),
+            Country::from_alpha2_bytes(country.alpha2().as_bytes())
+        );
+        assert_eq!(
+            Ok(country),
+            Country::from_alpha3_bytes(country.alpha3().to_ascii_lowercase())
+        );
+        assert_eq!(Ok(country), Country::from_numeric_bytes(country.code()));
+    }
+}
+
+#[test]
+fn fixed_size_arrays() {
+    let alpha2: [u8; 2] = *b"fr";
+    let alpha3: [u8; 3] = *b"FRA";
+    assert_eq!(Ok(Country::france()), Country::from_alpha2_bytes(alpha2));
+    assert_eq!(Ok(Country::france()), Country::from_alpha3_bytes(alpha3));
+    assert_eq!(Ok(Country::france()), Country::from_numeric_bytes(*b"250"));
+}
+
+#[test]
+fn rejects_invalid_bytes() {
+    assert_eq!(
+        Err("invalid alpha2"),
+        Country::from_alpha2_bytes([0xC3, 0x9F])
+    );
+    assert_eq!(Err("invalid alpha2"), Country::from_alpha2_bytes(b"D1"));
+    assert_eq!(Err("invalid alpha2"), Country::from_alpha2_bytes(b"DEU"));
+    assert_eq!(Err("invalid alpha2"), Country::from_alpha2_bytes(b"QQ"));
+    assert_eq!(Err("invalid alpha3"), Country::from_alpha3_bytes([0xFF; 3]));
+    assert_eq!(Err("invalid alpha3"), Country::from_alpha3_bytes(b""));
+    assert_eq!(Err("invalid code"), Country::from_numeric_bytes(b"04"));
+    assert_eq!(Err("invalid code"), Country::from_numeric_bytes(b"0x4"));
+    assert_eq!(Err("invalid code"), Country::from_numeric_bytes(b"999"));
+}
@@ -0,0 +1,33 @@
+//! Compile-time lookup tests
+use celes::Country;
+
+const WAREHOUSES: [Country; 3] = [
+    Country::from_alpha2_const("de").expect("valid alpha2"),
+    Country::from_alpha3_const("USA").expect("valid alpha3"),
+    Country::from_value_const(392).expect("valid value"),
+];
+
+#[test]
+fn evaluated_at_compile_time() {
+    assert_eq!(
+        [
+            Country::germany(),
+            Country::the_united_states_of_america(),
+            Country::japan()
+        ],
+        WAREHOUSES
+    );
+}
+
+#[test]
+fn matches_runtime_lookups() {
+    for country in Country::get_countries() {
+        assert_eq!(Some(country), Country::from_alpha2_const(country.alpha2()));
+        assert_eq!(Some(country), Country::from_alpha3_const(country.alpha3()));
+        assert_eq!(Some(country), Country::from_value_const(country.value()));
+    }
+    assert!(Country::from_alpha2_const("").is_none());
+    assert!(Country::from_alpha2_const("D\u{e9}").is_none());
+    assert!(Country::from_alpha3_const("DE").is_none());
+    assert!(Country::from_value_const(1000).is_none());
+}
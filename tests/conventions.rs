@@ -0,0 +1,41 @@
+//! Locale convention tests
+use celes::{Country, DateOrder, DrivingSide, MeasurementSystem, PaperSize, Weekday};
+
+#[test]
+fn conventions() {
+    let us = Country::the_united_states_of_america().conventions();
+    assert_eq!(us.driving_side, DrivingSide::Right);
+    assert_eq!(us.measurement_system, MeasurementSystem::UsCustomary);
+    assert_eq!(us.paper_size, PaperSize::Letter);
+    assert_eq!(us.first_day_of_week, Weekday::Sunday);
+    assert_eq!(us.date_order, DateOrder::Mdy);
+
+    let jp = Country::japan().conventions();
+    assert_eq!(jp.driving_side, DrivingSide::Left);
+    assert_eq!(jp.date_order, DateOrder::Ymd);
+
+    let fr = Country::france().conventions();
+    assert_eq!(fr.decimal_separator, ',');
+    assert_eq!(fr.grouping_separator, '\u{202f}');
+
+    let ir = Country::islamic_republic_of_iran().conventions();
+    assert_eq!(ir.first_day_of_week, Weekday::Saturday);
+    assert!(ir.is_weekend(Weekday::Friday));
+    assert!(!ir.is_weekend(Weekday::Saturday));
+
+    // The UAE moved its weekend to Saturday and Sunday in 2022
+    let ae = Country::the_united_arab_emirates().conventions();
+    assert_eq!(ae.first_day_of_week, Weekday::Monday);
+    assert!(ae.is_weekend(Weekday::Sunday));
+    assert!(!ae.is_weekend(Weekday::Friday));
+
+    let af = Country::afghanistan().conventions();
+    assert!(af.is_weekend(Weekday::Thursday));
+    assert!(af.is_weekend(Weekday::Friday));
+
+    let left = Country::get_countries()
+        .iter()
+        .filter(|c| c.conventions().driving_side == DrivingSide::Left)
+        .count();
+    assert_eq!(left, 77);
+}
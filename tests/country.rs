@@ -13,23 +13,33 @@ fn from_strings() {
         assert!(res.is_ok(), "from_name({})", c.to_string());
         assert_eq!(res.unwrap(), *c);
 
-        let res = Country::from_value(c.value);
-        assert!(res.is_ok(), "from_value({}) - {}", c.to_string(), c.value);
+        let res = Country::from_value(c.value());
+        assert!(res.is_ok(), "from_value({}) - {}", c.to_string(), c.value());
         assert_eq!(res.unwrap(), *c);
 
-        let res = Country::from_code(&c.code);
-        assert!(res.is_ok(), "from_code({}) - {}", c.to_string(), c.code);
+        let res = Country::from_code(c.code());
+        assert!(res.is_ok(), "from_code({}) - {}", c.to_string(), c.code());
         assert_eq!(res.unwrap(), *c);
 
-        let res = Country::from_alpha2(&c.alpha2);
-        assert!(res.is_ok(), "from_alpha2({}) - {}", c.to_string(), c.alpha2);
+        let res = Country::from_alpha2(c.alpha2());
+        assert!(
+            res.is_ok(),
+            "from_alpha2({}) - {}",
+            c.to_string(),
+            c.alpha2()
+        );
         assert_eq!(res.unwrap(), *c);
 
-        let res = Country::from_alpha3(&c.alpha3);
-        assert!(res.is_ok(), "from_alpha3({}) - {}", c.to_string(), c.alpha3);
+        let res = Country::from_alpha3(c.alpha3());
+        assert!(
+            res.is_ok(),
+            "from_alpha3({}) - {}",
+            c.to_string(),
+            c.alpha3()
+        );
         assert_eq!(res.unwrap(), *c);
 
-        for alias in c.aliases.iter() {
+        for alias in c.aliases().iter() {
             let res = Country::from_alias(*alias);
             assert!(res.is_ok(), "from_alias({}) - {}", c.to_string(), alias);
             assert_eq!(res.unwrap(), *c);
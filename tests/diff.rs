@@ -0,0 +1,80 @@
+//! Dataset diff tests
+#![cfg(feature = "std")]
+use celes::diff::{Difference, Record, Snapshot, diff};
+
+fn record(value: usize, alpha2: &str, alpha3: &str, name: &str, aliases: &[&str]) -> Record {
+    Record {
+        code: format!("{value:03}"),
+        value,
+        alpha2: alpha2.to_string(),
+        alpha3: alpha3.to_string(),
+        long_name: name.to_string(),
+        aliases: aliases.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[test]
+fn current_snapshot_round_trips() {
+    let current = Snapshot::current();
+    assert_eq!(250, current.countries.len());
+    let parsed = Snapshot::from_json(&current.to_json()).expect("valid export");
+    assert_eq!(current, parsed);
+    assert!(diff(&current, &parsed).is_empty());
+}
+
+#[test]
+fn structured_changes() {
+    let old = Snapshot::from(vec![
+        record(530, "AN", "ANT", "Netherlands Antilles", &[]),
+        record(104, "BU", "BUR", "Burma", &["Burma"]),
+        record(748, "SZ", "SWZ", "Swaziland", &["Swaziland"]),
+    ]);
+    let new = Snapshot::from(vec![
+        record(104, "MM", "MMR", "Myanmar", &["Myanmar", "Burma"]),
+        record(748, "SZ", "SWZ", "Eswatini", &["Eswatini", "Swaziland"]),
+        record(531, "CW", "CUW", "Curacao", &[]),
+    ]);
+    let changes = diff(&old, &new);
+    assert_eq!(
+        vec![
+            Difference::Renamed {
+                value: 104,
+                old: "Burma".to_string(),
+                new: "Myanmar".to_string(),
+            },
+            Difference::Recoded {
+                value: 104,
+                old: ("BU".to_string(), "BUR".to_string()),
+                new: ("MM".to_string(), "MMR".to_string()),
+            },
+            Difference::AliasAdded {
+                value: 104,
+                alias: "Myanmar".to_string(),
+            },
+            Difference::Removed {
+                country: record(530, "AN", "ANT", "Netherlands Antilles", &[]),
+            },
+            Difference::Renamed {
+                value: 748,
+                old: "Swaziland".to_string(),
+                new: "Eswatini".to_string(),
+            },
+            Difference::AliasAdded {
+                value: 748,
+                alias: "Eswatini".to_string(),
+            },
+            Difference::Added {
+                country: record(531, "CW", "CUW", "Curacao", &[]),
+            },
+        ],
+        changes
+    );
+    let reverse = diff(&new, &old);
+    assert!(reverse.contains(&Difference::AliasRemoved {
+        value: 104,
+        alias: "Myanmar".to_string(),
+    }));
+
+    let json = serde_json::to_value(&changes[0]).expect("serializable");
+    assert_eq!("renamed", json["kind"]);
+}
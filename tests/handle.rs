@@ -0,0 +1,49 @@
+//! Compact country handle tests
+use celes::{Country, LookupTable};
+
+#[test]
+fn handle_is_two_bytes() {
+    assert_eq!(2, size_of::<Country>());
+    assert_eq!(2, size_of::<Option<Country>>());
+}
+
+#[test]
+fn accessors_read_static_tables() {
+    let gb = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
+    assert_eq!("826", gb.code());
+    assert_eq!(826, gb.value());
+    assert_eq!("GB", gb.alpha2());
+    assert_eq!("GBR", gb.alpha3());
+    assert_eq!(
+        "The United Kingdom Of Great Britain And Northern Ireland",
+        gb.long_name()
+    );
+    assert!(gb.aliases().contains("england"));
+}
+
+#[test]
+#[allow(deprecated)]
+fn fields_still_readable() {
+    for c in Country::all() {
+        assert_eq!(c.code(), c.code);
+        assert_eq!(c.value(), c.value);
+        assert_eq!(c.alpha2(), c.alpha2);
+        assert_eq!(c.alpha3(), c.alpha3);
+        assert_eq!(c.long_name(), c.long_name);
+        assert_eq!(c.aliases(), c.aliases);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn runtime_handles() {
+    let a = Country::user_assigned("905", 905, "XE", "XEE", "Elsewhere").expect("user-assigned");
+    let b = Country::user_assigned("905", 905, "XE", "XEE", "Elsewhere").expect("user-assigned");
+    assert_eq!(a, b);
+    assert_eq!("XE", a.alpha2());
+    assert_eq!("Elsewhere", a.long_name());
+    assert_eq!(
+        "Country { code: 905, value: 905, alpha2: XE, alpha3: XEE, long_name: Elsewhere, aliases: [] }",
+        format!("{a:?}")
+    );
+}
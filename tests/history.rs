@@ -0,0 +1,129 @@
+//! Change history tests
+use celes::{ChangeKind, Country, DATA_VERSION, Date, HISTORY};
+
+fn date(s: &str) -> Date {
+    s.parse().expect("valid date")
+}
+
+#[test]
+fn history_is_ordered() {
+    assert!(HISTORY.windows(2).all(|w| w[0].date <= w[1].date));
+    let last = HISTORY.last().expect("history is not empty");
+    assert_eq!(DATA_VERSION, last.date.to_string());
+}
+
+#[test]
+fn date_parsing() {
+    assert_eq!(Date::new(2010, 12, 15), "2010-12-15".parse().ok());
+    assert!("2010-13-01".parse::<Date>().is_err());
+    assert!("2010-1-1".parse::<Date>().is_err());
+    assert!(Date::new(2000, 2, 29).is_some());
+    assert!(Date::new(1900, 2, 29).is_none());
+}
+
+#[test]
+fn current_entries_match_countries() {
+    let today = date(DATA_VERSION);
+    let entries: Vec<_> = Country::as_of(today).collect();
+    assert_eq!(Country::get_countries().len(), entries.len());
+    for entry in entries {
+        let country = entry.country().expect("current entry");
+        assert_eq!(country.long_name(), entry.name);
+        assert_eq!(country.alpha3(), entry.alpha3);
+    }
+}
+
+#[test]
+fn as_of_history() {
+    let before = date("2006-01-01");
+    let codes: Vec<_> = Country::as_of(before).map(|e| e.alpha2).collect();
+    assert!(codes.contains(&"CS"));
+    assert!(codes.contains(&"AN"));
+    assert!(codes.contains(&"AX"));
+    assert!(!codes.contains(&"RS"));
+    assert!(!codes.contains(&"ME"));
+    assert!(!codes.contains(&"GG"));
+    assert!(!codes.contains(&"YU"));
+
+    let old = date("1990-01-01");
+    let codes: Vec<_> = Country::as_of(old).map(|e| e.alpha2).collect();
+    assert!(codes.contains(&"ZR"));
+    assert!(codes.contains(&"YU"));
+    assert!(!codes.contains(&"CD"));
+}
+
+#[test]
+fn from_str_as_of() {
+    let entry = Country::from_str_as_of("scg", date("2005-05-05")).expect("Serbia and Montenegro");
+    assert_eq!("CS", entry.alpha2);
+    assert_eq!(Some(date("2003-07-23")), entry.valid_from);
+    assert_eq!(Some(date("2006-09-26")), entry.valid_until);
+    assert!(entry.country().is_none());
+
+    let entry = Country::from_str_as_of("SD", date("2010-01-01")).expect("old Sudan");
+    assert_eq!(736, entry.value);
+    let entry = Country::from_str_as_of("SD", date("2012-01-01")).expect("new Sudan");
+    assert_eq!(729, entry.value);
+    assert_eq!(Some(Country::the_sudan()), entry.country());
+
+    let entry = Country::from_str_as_of("NetherlandsAntilles", date("2001-01-01")).expect("name");
+    assert_eq!("AN", entry.alpha2);
+
+    let entry = Country::from_str_as_of("Eswatini", date("2015-01-01")).expect("renamed");
+    assert_eq!("Swaziland", entry.name);
+    let entry = Country::from_str_as_of("SZ", date("2020-01-01")).expect("renamed");
+    assert_eq!("Eswatini", entry.name);
+
+    assert!(Country::from_str_as_of("SS", date("2010-01-01")).is_err());
+    assert!(Country::from_str_as_of("AN", date("2011-01-01")).is_err());
+}
+
+#[test]
+fn renames_are_recorded() {
+    let renamed: Vec<_> = HISTORY
+        .iter()
+        .filter_map(|c| match c.kind {
+            ChangeKind::Renamed { old } => Some((old, c.name)),
+            _ => None,
+        })
+        .collect();
+    assert!(renamed.contains(&("Turkey", "Türkiye")));
+    assert!(renamed.contains(&("Swaziland", "Eswatini")));
+}
+
+#[test]
+fn rename_aware_resolution() {
+    for (input, old, new) in [
+        ("Swaziland", "Swaziland", "Eswatini"),
+        ("burma", "Burma", "Myanmar"),
+        ("CapeVerde", "Cape Verde", "Cabo Verde"),
+        (
+            "Macedonia",
+            "The Former Yugoslav Republic Of Macedonia",
+            "Republic Of North Macedonia",
+        ),
+        ("TURKEY", "Turkey", "Türkiye"),
+        ("EastTimor", "East Timor", "Timor Leste"),
+        ("Czech Republic", "Czech Republic", "Czechia"),
+        (
+            "TheFormerYugoslavRepublicOfMacedonia",
+            "The Former Yugoslav Republic Of Macedonia",
+            "Republic Of North Macedonia",
+        ),
+    ] {
+        let (country, renamed) = Country::from_str_renamed(input).expect(input);
+        let renamed = renamed.expect(input);
+        assert_eq!(old, renamed.old);
+        assert_eq!(new, renamed.new);
+        assert_eq!(country.long_name(), renamed.new);
+    }
+    for input in ["Myanmar", "MM", "CaboVerde", "Türkiye", "TR", "792", "MKD"] {
+        let (_, renamed) = Country::from_str_renamed(input).expect(input);
+        assert!(renamed.is_none(), "{input}");
+    }
+    assert!(Country::from_str_renamed("Atlantis").is_err());
+    assert_eq!(
+        Some(date("1989-12-05")),
+        Country::myanmar().renamed().map(|r| r.since)
+    );
+}
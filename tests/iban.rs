@@ -0,0 +1,87 @@
+//! IBAN tests
+use celes::{
+    Country,
+    iban::{self, IbanError},
+};
+
+#[test]
+fn formats_are_consistent() {
+    for c in &Country::get_countries() {
+        let Some(format) = c.iban_format() else {
+            continue;
+        };
+        let bban_len: usize = format
+            .bban
+            .split('!')
+            .filter_map(|g| {
+                g.trim_start_matches(['n', 'a', 'c', 'e'])
+                    .parse::<usize>()
+                    .ok()
+            })
+            .sum();
+        assert_eq!(format.length, bban_len + 4, "{}", c.alpha2());
+        assert!(format.length <= 34, "{}", c.alpha2());
+    }
+}
+
+#[test]
+fn validate() {
+    for (alpha2, input) in [
+        ("DE", "DE89370400440532013000"),
+        ("GB", "GB82WEST12345698765432"),
+        ("FR", "FR14 2004 1010 0505 0001 3M02 606"),
+        ("NL", "nl91abna0417164300"),
+        ("BE", "BE68539007547034"),
+        ("CH", "CH9300762011623852957"),
+        ("ES", "ES9121000418450200051332"),
+        ("IT", "IT60X0542811101000000123456"),
+        ("AT", "AT611904300234573201"),
+        ("NO", "NO9386011117947"),
+        ("PL", "PL61109010140000071219812874"),
+        ("SA", "SA0380000000608010167519"),
+        ("MT", "MT84MALT011000012345MTLCAST001S"),
+    ] {
+        let res = iban::validate(input);
+        assert!(res.is_ok(), "validate({input}) - {res:?}");
+        let (country, iban) = res.expect("valid");
+        assert_eq!(country.alpha2(), alpha2);
+        assert_eq!(iban.country_code(), alpha2);
+    }
+
+    assert_eq!(
+        iban::validate("DE8937040044053201300"),
+        Err(IbanError::InvalidLength {
+            expected: 22,
+            actual: 21
+        })
+    );
+    assert_eq!(
+        iban::validate("NL91ABNA041716430A"),
+        Err(IbanError::InvalidFormat)
+    );
+    assert_eq!(
+        iban::validate("NL91-ABNA-0417-1643-00"),
+        Err(IbanError::InvalidCharacter)
+    );
+}
+
+#[test]
+fn length_reported_for_resolved_country() {
+    assert_eq!(
+        iban::validate("DE8"),
+        Err(IbanError::InvalidLength {
+            expected: 22,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        iban::validate("DE89 3704 0044 0532 0130 0012 3456 7890 1234"),
+        Err(IbanError::InvalidLength {
+            expected: 22,
+            actual: 36
+        })
+    );
+    assert_eq!(iban::validate("D"), Err(IbanError::UnknownCountry));
+    assert_eq!(iban::validate(""), Err(IbanError::UnknownCountry));
+    assert_eq!(iban::validate("ZZ8"), Err(IbanError::UnknownCountry));
+}
@@ -0,0 +1,38 @@
+//! Direct-index code lookup tests
+use celes::Country;
+use core::str::FromStr;
+
+#[test]
+fn every_code_round_trips() {
+    for country in Country::get_countries() {
+        assert_eq!(Ok(country), Country::from_value(country.value()));
+        assert_eq!(Ok(country), Country::from_code(country.code()));
+        assert_eq!(
+            Ok(country),
+            Country::from_alpha2(country.alpha2().to_ascii_lowercase())
+        );
+        assert_eq!(Ok(country), Country::from_alpha3(country.alpha3()));
+        assert_eq!(Ok(country), Country::from_str(country.alpha2()));
+        assert_eq!(
+            Ok(country),
+            Country::from_str(&country.alpha3().to_ascii_lowercase())
+        );
+        assert_eq!(Ok(country), Country::from_str(country.code()));
+    }
+}
+
+#[test]
+fn rejects_bytes_next_to_letters_and_digits() {
+    for alpha2 in ["@E", "D[", "`E", "D{", "D\u{e9}", "D"] {
+        assert!(Country::from_alpha2(alpha2).is_err(), "{alpha2}");
+    }
+    for alpha3 in ["@EU", "DE[", "DE`", "{EU", "DE\u{e9}"] {
+        assert!(Country::from_alpha3(alpha3).is_err(), "{alpha3}");
+    }
+    for code in ["/04", "00:", "04", "0004", "999"] {
+        assert!(Country::from_code(code).is_err(), "{code}");
+    }
+    assert!(Country::from_value(1000).is_err());
+    assert!(Country::from_value(usize::MAX).is_err());
+    assert!(Country::from_value(0).is_err());
+}
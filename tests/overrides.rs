@@ -0,0 +1,154 @@
+//! Runtime override tests
+#![cfg(feature = "std")]
+use celes::{
+    Country, LookupTable,
+    overrides::{self, OverrideError, Overrides},
+};
+use core::{cmp::Ordering, str::FromStr};
+use std::collections::BTreeSet;
+
+// Overrides are process-wide so everything runs in one test to avoid interference
+#[test]
+#[allow(clippy::too_many_lines)]
+fn overrides_layer_over_tables() {
+    assert!(overrides::installed().is_empty());
+
+    let json = r#"[
+        {"code": "792", "value": 792, "alpha2": "TR", "alpha3": "TUR", "long_name": "Republic Of Turkiye", "aliases": ["Turkiye Cumhuriyeti"]},
+        {"code": "999", "value": 999, "alpha2": "qq", "alpha3": "qqq", "long_name": "Newland"}
+    ]"#;
+    overrides::install(Overrides::from_json(json).expect("valid json"));
+    assert_eq!(2, overrides::installed().len());
+
+    let tr = Country::from_alpha2("tr").expect("override");
+    assert_eq!("Republic Of Turkiye", tr.long_name());
+    assert_eq!(tr, Country::turkiye());
+    assert_eq!(Ordering::Equal, tr.cmp(&Country::turkiye()));
+    assert_eq!(Country::turkiye().index(), tr.index());
+    assert_eq!(Some(tr.index()), tr.checked_index());
+    assert_eq!(1, BTreeSet::from([tr, Country::turkiye()]).len());
+    assert_eq!(&["Turkiye Cumhuriyeti"], tr.aliases().as_slice());
+    assert!(!tr.aliases().contains("turkey"));
+    let mentioned: Vec<_> = celes::text::find_countries("Flights to Turkey")
+        .map(|(c, _, _)| c.long_name())
+        .collect();
+    assert_eq!(vec!["Republic Of Turkiye"], mentioned);
+    assert_eq!(
+        tr.long_name(),
+        Country::from_value(792).expect("value").long_name()
+    );
+    assert_eq!(
+        tr.long_name(),
+        Country::from_code("792").expect("code").long_name()
+    );
+    assert_eq!(
+        tr.long_name(),
+        Country::from_alias("turkey")
+            .expect("builtin alias")
+            .long_name()
+    );
+    assert_eq!(
+        tr.long_name(),
+        Country::from_alias("TurkiyeCumhuriyeti")
+            .expect("override alias")
+            .long_name()
+    );
+    assert_eq!(
+        tr.long_name(),
+        Country::from_name("RepublicOfTurkiye")
+            .expect("name")
+            .long_name()
+    );
+    let parser = celes::Parser::default();
+    for input in [
+        "republic_of_turkiye",
+        "RepublicOfTurkiye",
+        "turkiye_cumhuriyeti",
+    ] {
+        assert_eq!(
+            Country::from_str(input).expect(input).long_name(),
+            parser.parse(input).expect(input).long_name()
+        );
+    }
+
+    let new = Country::from_str("QQQ").expect("new entry");
+    assert_eq!("QQ", new.alpha2());
+    assert_eq!(999, new.value());
+    assert!(new.checked_index().is_none());
+    assert!(new.aliases().is_empty());
+    let de: Country = serde_json::from_str("\"QQ\"").expect("deserialize");
+    assert_eq!(new, de);
+    assert_eq!("\"QQ\"", serde_json::to_string(&de).expect("serialize"));
+
+    let csv = "code,value,alpha2,alpha3,long_name,aliases\n\
+               276,276,DE,DEU,\"Germany, Federal Republic\",Deutschland|BRD\n\
+               748,748,EW,EWZ,Eswatini,\n";
+    overrides::install(Overrides::from_csv(csv).expect("valid csv"));
+    let de = Country::from_str("brd").expect("override alias");
+    assert_eq!(&["Deutschland", "BRD"], de.aliases().as_slice());
+    assert!(de.aliases().contains("deutschland"));
+    assert_eq!(
+        "Türkiye",
+        Country::from_alpha2("TR").expect("table").long_name()
+    );
+    assert!(Country::from_alpha2("QQ").is_err());
+    assert_eq!(
+        "Germany, Federal Republic",
+        Country::from_str("brd")
+            .expect("override alias")
+            .long_name()
+    );
+
+    // Codes an override replaced no longer resolve, but names and aliases still do
+    let ew = Country::from_value(748).expect("value");
+    assert_eq!("EW", ew.alpha2());
+    assert_eq!(ew, Country::from_alpha2("ew").expect("new alpha2"));
+    assert_eq!(Err("invalid alpha2"), Country::from_alpha2("SZ"));
+    assert_eq!(Err("invalid alpha3"), Country::from_alpha3("SWZ"));
+    assert_eq!(Err("unknown value"), Country::from_str("SZ"));
+    assert_eq!("EW", Country::from_str("748").expect("code").alpha2());
+    assert_eq!("EW", Country::from_name("Eswatini").expect("name").alpha2());
+    let mentioned: Vec<_> = celes::text::find_countries("SZ, EW and Swaziland")
+        .codes(true)
+        .map(|(c, _, _)| c.alpha2())
+        .collect();
+    assert_eq!(vec!["EW", "EW"], mentioned);
+    assert_eq!(
+        "EW",
+        Country::from_alias("swaziland").expect("alias").alpha2()
+    );
+
+    overrides::clear();
+    assert_eq!("SZ", Country::from_alpha2("SZ").expect("table").alpha2());
+    assert_eq!(
+        "Germany",
+        Country::from_alpha2("DE").expect("table").long_name()
+    );
+    assert!(Country::from_str("brd").is_err());
+}
+
+#[test]
+fn invalid_overrides() {
+    let res = Overrides::from_json(
+        r#"[{"code": "79", "value": 792, "alpha2": "TR", "alpha3": "TUR", "long_name": "X"}]"#,
+    );
+    assert!(matches!(
+        res,
+        Err(OverrideError::InvalidRecord { index: 0, .. })
+    ));
+    let res = Overrides::from_csv(
+        "code,value,alpha2,alpha3,long_name\n792,792,TR,TUR,A\n792,792,TR,TUR,B\n",
+    );
+    assert!(matches!(
+        res,
+        Err(OverrideError::InvalidRecord { index: 1, .. })
+    ));
+    assert!(matches!(
+        Overrides::from_json("{"),
+        Err(OverrideError::Json(_))
+    ));
+    assert!(matches!(
+        Overrides::from_path("countries.xml"),
+        Err(OverrideError::UnknownFormat)
+    ));
+}
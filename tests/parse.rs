@@ -0,0 +1,171 @@
+//! Parse options tests
+use celes::{Country, ParseOptions, Parser};
+use core::str::FromStr;
+
+#[test]
+fn default_matches_from_str() {
+    let parser = Parser::default();
+    for input in [
+        "GB",
+        "gbr",
+        "826",
+        "england",
+        "TheUnitedKingdomOfGreatBritainAndNorthernIreland",
+        "the_united_kingdom_of_great_britain_and_northern_ireland",
+        "Swaziland",
+        "United States",
+        "unitedstates",
+    ] {
+        assert_eq!(
+            Country::from_str(input).expect(input),
+            parser.parse(input).expect(input)
+        );
+    }
+    assert!(parser.parse(" GB").is_err());
+    assert!(parser.parse("4").is_err());
+    assert!(parser.parse("").is_err());
+}
+
+#[test]
+fn identifier_kinds() {
+    let codes = ParseOptions::none().alpha2(true).alpha3(true).build();
+    assert_eq!(Country::france(), codes.parse("FR").expect("alpha2"));
+    assert_eq!(Country::france(), codes.parse("fra").expect("alpha3"));
+    assert!(codes.parse("250").is_err());
+    assert!(codes.parse("France").is_err());
+
+    let names = ParseOptions::none().names(true).build();
+    assert_eq!(
+        Country::the_netherlands(),
+        names.parse("TheNetherlands").expect("name")
+    );
+    assert_eq!(
+        Country::the_netherlands(),
+        names.parse("the_netherlands").expect("snake case")
+    );
+    assert!(names.parse("Holland").is_err());
+    assert!(names.parse("NL").is_err());
+
+    let aliases = ParseOptions::none().aliases(true).build();
+    assert_eq!(
+        Country::the_netherlands(),
+        aliases.parse("holland").expect("alias")
+    );
+    assert!(aliases.parse("NLD").is_err());
+}
+
+#[test]
+fn case_sensitivity() {
+    let parser = ParseOptions::new().case_sensitive(true).build();
+    assert!(parser.parse("GB").is_ok());
+    assert!(parser.parse("Gb").is_err());
+    assert!(parser.parse("GBR").is_ok());
+    assert!(parser.parse("gbr").is_err());
+    assert!(parser.parse("TheNetherlands").is_ok());
+    assert!(parser.parse("thenetherlands").is_err());
+    assert!(parser.parse("the_netherlands").is_ok());
+    assert!(parser.parse("The_Netherlands").is_err());
+    assert!(parser.parse("Holland").is_ok());
+    assert!(parser.parse("HOLLAND").is_err());
+}
+
+#[test]
+fn deprecated_aliases() {
+    let parser = ParseOptions::new().deprecated_aliases(false).build();
+    for outdated in ["Swaziland", "burma", "CapeVerde", "Turkey", "Macedonia"] {
+        assert!(parser.parse(outdated).is_err(), "{outdated}");
+    }
+    assert_eq!(
+        Country::eswatini(),
+        parser.parse("Eswatini").expect("current")
+    );
+    assert_eq!(
+        Country::myanmar(),
+        parser.parse("Myanmar").expect("current")
+    );
+}
+
+#[test]
+fn numeric_and_trimming() {
+    let parser = ParseOptions::new()
+        .unpadded_numeric(true)
+        .trim(true)
+        .build();
+    assert_eq!(
+        Country::afghanistan(),
+        parser.parse(" 4 ").expect("one digit")
+    );
+    assert_eq!(Country::albania(), parser.parse("08").expect("two digits"));
+    assert_eq!(
+        Country::albania(),
+        parser.parse("008").expect("three digits")
+    );
+    assert!(parser.parse("0008").is_err());
+    assert_eq!(Country::germany(), parser.parse("\tDE\n").expect("trimmed"));
+    assert!(parser.parse("   ").is_err());
+    assert!(
+        ParseOptions::new()
+            .numeric(false)
+            .unpadded_numeric(true)
+            .build()
+            .parse("4")
+            .is_err()
+    );
+}
+
+#[test]
+fn detailed_parse() {
+    use celes::MatchKind;
+
+    for (input, kind, case_folded) in [
+        ("GB", MatchKind::Alpha2, false),
+        ("gb", MatchKind::Alpha2, true),
+        ("GBR", MatchKind::Alpha3, false),
+        ("Gbr", MatchKind::Alpha3, true),
+        ("826", MatchKind::Numeric, false),
+        (
+            "TheUnitedKingdomOfGreatBritainAndNorthernIreland",
+            MatchKind::Name,
+            false,
+        ),
+        (
+            "theunitedkingdomofgreatbritainandnorthernireland",
+            MatchKind::Name,
+            true,
+        ),
+        (
+            "the_united_kingdom_of_great_britain_and_northern_ireland",
+            MatchKind::SnakeCaseName,
+            false,
+        ),
+        (
+            "The_United_Kingdom_Of_Great_Britain_And_Northern_Ireland",
+            MatchKind::SnakeCaseName,
+            true,
+        ),
+        ("England", MatchKind::Alias, false),
+        ("ENGLAND", MatchKind::Alias, true),
+    ] {
+        let details = Country::parse_detailed(input).expect(input);
+        assert_eq!("GB", details.country.alpha2(), "{input}");
+        assert_eq!(kind, details.kind, "{input}");
+        assert_eq!(case_folded, details.case_folded, "{input}");
+    }
+    let details = Country::parse_detailed("United States").expect("from_str name");
+    assert_eq!(Country::the_united_states_of_america(), details.country);
+    assert_eq!(MatchKind::Alias, details.kind);
+    assert!(Country::parse_detailed("Atlantis").is_err());
+    assert!(Country::parse_detailed("4").is_err());
+}
+
+#[test]
+fn detailed_parse_agrees_with_from_str() {
+    for country in Country::get_countries() {
+        for input in [country.alpha2(), country.alpha3(), country.code()] {
+            assert_eq!(
+                Country::from_str(input).expect(input),
+                Country::parse_detailed(input).expect(input).country
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Postal code tests
+use celes::Country;
+
+#[test]
+fn examples_are_canonical() {
+    for c in &Country::get_countries() {
+        let format = c.postal_code_format();
+        if !format.is_used() {
+            assert!(format.example.is_none(), "{}", c.alpha2());
+            assert!(!c.validate_postal_code(""), "{}", c.alpha2());
+            continue;
+        }
+        let example = format.example.expect("example");
+        let res = c.normalize_postal_code(example);
+        assert!(
+            res.is_ok(),
+            "normalize_postal_code({}) - {example}",
+            c.alpha2()
+        );
+        assert_eq!(res.expect("normalized"), example, "{}", c.alpha2());
+    }
+}
+
+#[test]
+fn normalization() {
+    let nl = Country::the_netherlands();
+    assert_eq!(
+        nl.normalize_postal_code("1234ab").expect("valid"),
+        "1234 AB"
+    );
+    assert!(!nl.validate_postal_code("1234 A"));
+
+    let ad = Country::andorra();
+    assert!(ad.validate_postal_code("ad500"));
+    assert!(!ad.validate_postal_code("AB500"));
+
+    let ie = Country::ireland();
+    assert_eq!(
+        ie.normalize_postal_code("d6wxy12").expect("valid"),
+        "D6W XY12"
+    );
+
+    let pl = Country::poland();
+    assert_eq!(pl.normalize_postal_code("00 950").expect("valid"), "00-950");
+    assert!(!pl.validate_postal_code("00-9500"));
+}
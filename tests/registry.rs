@@ -0,0 +1,94 @@
+//! Alias registry tests
+#![cfg(feature = "std")]
+use celes::{AliasError, AliasRegistry, Country, MatchKind, Parser};
+use core::str::FromStr;
+
+fn tenant_registry() -> AliasRegistry {
+    let mut registry = AliasRegistry::new();
+    let gb = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
+    registry.register("Blighty", gb).expect("Blighty");
+    registry
+        .register("KSA", Country::saudi_arabia())
+        .expect("KSA");
+    registry
+        .register("UAE-Dubai", Country::the_united_arab_emirates())
+        .expect("UAE-Dubai");
+    registry
+        .register("Holland (NL)", Country::the_netherlands())
+        .expect("Holland (NL)");
+    registry
+}
+
+#[test]
+fn register_and_lookup() {
+    let mut registry = tenant_registry();
+    assert_eq!(4, registry.len());
+    assert_eq!(
+        Some(Country::the_netherlands()),
+        registry.get("HOLLAND (nl)")
+    );
+    assert_eq!(Some(Country::saudi_arabia()), registry.get(" ksa "));
+    assert_eq!(None, registry.get("Narnia"));
+
+    assert_eq!(
+        Ok(()),
+        registry.register("blighty", registry.get("Blighty").expect("gb"))
+    );
+    assert_eq!(
+        Err(AliasError::AlreadyRegistered("GB")),
+        registry.register("BLIGHTY", Country::ireland())
+    );
+    assert_eq!(
+        Err(AliasError::Empty),
+        registry.register("  ", Country::ireland())
+    );
+    for builtin in [
+        "DE",
+        "deu",
+        "276",
+        "Germany",
+        "United Kingdom",
+        "great_britain",
+    ] {
+        assert!(matches!(
+            registry.register(builtin, Country::ireland()),
+            Err(AliasError::ConflictsWithBuiltin(_))
+        ));
+    }
+    assert_eq!(Some(Country::saudi_arabia()), registry.remove("Ksa"));
+    assert_eq!(3, registry.len());
+}
+
+#[test]
+fn serde_round_trip() {
+    let registry = tenant_registry();
+    let json = serde_json::to_string(&registry).expect("serialize");
+    assert_eq!(
+        r#"{"blighty":"GB","holland (nl)":"NL","ksa":"SA","uae-dubai":"AE"}"#,
+        json
+    );
+    let parsed: AliasRegistry = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(registry, parsed);
+    assert!(serde_json::from_str::<AliasRegistry>(r#"{"france":"DE"}"#).is_err());
+}
+
+#[test]
+fn from_str_consults_installed_registry() {
+    assert!(Country::from_str("Blighty").is_err());
+    tenant_registry().install();
+    assert_eq!(
+        "GB",
+        Country::from_str("blighty").expect("registered").alpha2()
+    );
+    assert_eq!("DE", Country::from_str("DE").expect("builtin").alpha2());
+    assert_eq!(
+        Country::from_str("blighty"),
+        Parser::default().parse("blighty")
+    );
+    assert_eq!(
+        MatchKind::Alias,
+        Country::parse_detailed("blighty").expect("registered").kind
+    );
+    AliasRegistry::uninstall();
+    assert!(Country::from_str("Blighty").is_err());
+}
@@ -0,0 +1,58 @@
+//! Ambiguity-aware resolution tests
+use celes::{Confidence, Country};
+
+#[test]
+fn ambiguous_inputs() {
+    for (input, expected) in [
+        ("Congo", &["CG", "CD"][..]),
+        ("korea", &["KR", "KP"]),
+        ("GUINEA", &["GN", "GW", "GQ", "PG"]),
+        ("Samoa", &["WS", "AS"]),
+        ("Virgin Islands", &["VG", "VI"]),
+        ("Sudan", &["SD", "SS"]),
+        ("St. Martin", &["MF", "SX"]),
+        ("Saint-Martin", &["MF", "SX"]),
+        ("Sint Maarten", &["SX", "MF"]),
+    ] {
+        let resolution = Country::resolve(input);
+        assert!(resolution.is_ambiguous(), "{input}");
+        assert!(resolution.unique().is_none(), "{input}");
+        let codes: Vec<_> = resolution
+            .candidates()
+            .map(|c| c.country.alpha2())
+            .collect();
+        assert_eq!(expected, codes.as_slice(), "{input}");
+        let ranks: Vec<_> = resolution.candidates().map(|c| c.confidence).collect();
+        assert!(ranks.windows(2).all(|w| w[0] >= w[1]), "{input}");
+        assert!(ranks.iter().all(|c| *c < Confidence::Exact), "{input}");
+    }
+}
+
+#[test]
+fn unambiguous_inputs() {
+    for (input, alpha2) in [
+        ("CD", "CD"),
+        ("COG", "CG"),
+        ("DemocraticRepublicOfTheCongo", "CD"),
+        ("South Korea", "KR"),
+        ("the_sudan", "SD"),
+        ("American Samoa", "AS"),
+        ("Guinea-Bissau", "GW"),
+        ("Papua New Guinea", "PG"),
+        ("882", "WS"),
+    ] {
+        let resolution = Country::resolve(input);
+        assert!(!resolution.is_ambiguous(), "{input}");
+        assert_eq!(
+            Some(alpha2),
+            resolution.unique().map(Country::alpha2),
+            "{input}"
+        );
+        let candidate = resolution.candidates().next().expect(input);
+        assert_eq!(Confidence::Exact, candidate.confidence);
+    }
+    let resolution = Country::resolve("Atlantis");
+    assert!(resolution.is_empty());
+    assert!(!resolution.is_ambiguous());
+    assert_eq!(0, resolution.candidates().count());
+}
@@ -0,0 +1,80 @@
+//! Prefix search tests
+use celes::{Country, SearchRank};
+
+fn alpha2(prefix: &str, limit: usize) -> Vec<&'static str> {
+    Country::search_prefix(prefix, limit)
+        .map(|m| m.country.alpha2())
+        .collect()
+}
+
+#[test]
+fn ranking() {
+    let matches: Vec<_> = Country::search_prefix("uni", 20).collect();
+    let ranks: Vec<_> = matches.iter().map(|m| m.rank).collect();
+    let mut sorted = ranks.clone();
+    sorted.sort();
+    assert_eq!(sorted, ranks);
+    assert_eq!(
+        vec!["AE", "GB", "UM", "US", "TZ"],
+        matches
+            .iter()
+            .filter(|m| m.rank == SearchRank::NameStart)
+            .map(|m| m.country.alpha2())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn exact_codes_first() {
+    let matches: Vec<_> = Country::search_prefix("gb", 5).collect();
+    assert_eq!(
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+        matches[0].country
+    );
+    assert_eq!(SearchRank::Code, matches[0].rank);
+    assert_eq!("GB", matches[0].matched);
+
+    let best = Country::search_prefix(" 276 ", 1)
+        .next()
+        .expect("numeric code");
+    assert_eq!(Country::germany(), best.country);
+    assert_eq!("276", best.matched);
+}
+
+#[test]
+fn word_start_and_alias() {
+    let word = Country::search_prefix("kingdom", 5)
+        .next()
+        .expect("word start");
+    assert_eq!(SearchRank::WordStart, word.rank);
+    assert_eq!("GB", word.country.alpha2());
+
+    let alias = Country::search_prefix("holl", 5).next().expect("alias");
+    assert_eq!(Country::the_netherlands(), alias.country);
+    assert_eq!(SearchRank::Alias, alias.rank);
+    assert_eq!("Holland", alias.matched);
+
+    let alias = Country::search_prefix("ivory co", 5)
+        .next()
+        .expect("alias with space");
+    assert_eq!("IvoryCoast", alias.matched);
+}
+
+#[test]
+fn each_country_once() {
+    let gb = Country::the_united_kingdom_of_great_britain_and_northern_ireland();
+    assert_eq!(
+        1,
+        Country::search_prefix("united", 250)
+            .filter(|m| m.country == gb)
+            .count()
+    );
+}
+
+#[test]
+fn limits_and_empty_prefix() {
+    assert_eq!(2, alpha2("s", 2).len());
+    assert!(alpha2("s", 0).is_empty());
+    assert!(alpha2("  ", 10).is_empty());
+    assert!(alpha2("zzzz", 10).is_empty());
+}
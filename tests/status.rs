@@ -0,0 +1,86 @@
+//! Code assignment status tests
+use celes::{CodeStatus, Country};
+
+#[test]
+fn classify_codes() {
+    for code in ["UK", "EU", "UN", "EA", "IC", "AC", "DG"] {
+        assert!(matches!(
+            CodeStatus::of(code),
+            CodeStatus::ExceptionallyReserved(_)
+        ));
+    }
+    for code in ["AN", "BU", "CS", "NT", "TP", "YU", "ANT", "YUG"] {
+        assert!(matches!(
+            CodeStatus::of(code),
+            CodeStatus::TransitionallyReserved(_)
+        ));
+    }
+    for code in [
+        "AA", "QM", "QZ", "XA", "XZ", "ZZ", "AAA", "QMA", "XXX", "zzz",
+    ] {
+        assert_eq!(CodeStatus::UserAssigned, CodeStatus::of(code));
+    }
+    assert_eq!(CodeStatus::Unassigned, CodeStatus::of("QL"));
+    assert_eq!(CodeStatus::Unassigned, CodeStatus::of("1A"));
+    assert_eq!(CodeStatus::Unassigned, CodeStatus::of("ABCD"));
+    assert_eq!(CodeStatus::of("XKX"), Country::kosovo().code_status());
+}
+
+#[test]
+fn countries_are_officially_assigned() {
+    for country in Country::get_countries() {
+        let expected = if country.alpha2() == "XK" {
+            CodeStatus::UserAssigned
+        } else {
+            CodeStatus::OfficiallyAssigned
+        };
+        assert_eq!(expected, country.code_status());
+        assert_eq!(expected, CodeStatus::of(country.alpha2()));
+        assert_eq!(expected, CodeStatus::of(country.alpha3()));
+    }
+}
+
+#[test]
+fn conventional_codes() {
+    let res = Country::from_alpha2_conventional("el").expect("EL resolves");
+    assert_eq!(Country::greece(), res.country);
+    assert!(res.non_standard);
+    let res = Country::from_alpha2_conventional("UK").expect("UK resolves");
+    assert_eq!("GB", res.country.alpha2());
+    assert!(res.non_standard);
+    let res = Country::from_alpha2_conventional("DE").expect("DE resolves");
+    assert_eq!(Country::germany(), res.country);
+    assert!(!res.non_standard);
+    assert!(Country::from_alpha2_conventional("QQ").is_err());
+    assert!(Country::from_alpha2("UK").is_err());
+    assert!(Country::from_alpha2("EL").is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn user_assigned_territories() {
+    use celes::LookupTable;
+
+    let waters = Country::user_assigned("901", 901, "XA", "XAA", "International Waters")
+        .expect("user-assigned");
+    assert_eq!(CodeStatus::UserAssigned, waters.code_status());
+    assert!(
+        Country::user_assigned("902", 902, "QO", "QOO", "Outlying Oceania")
+            .expect("user-assigned")
+            .aliases()
+            .is_empty()
+    );
+    for (code, value, alpha2, alpha3) in [
+        ("900", 900, "GB", "XGB"),
+        ("900", 900, "XG", "GBR"),
+        ("900", 900, "XK", "XKA"),
+        ("900", 900, "XA", "XKX"),
+        ("900", 900, "xa", "XAA"),
+        ("383", 383, "XA", "XAA"),
+        ("90", 90, "XA", "XAA"),
+        ("901", 900, "XA", "XAA"),
+        ("826", 826, "QM", "QMM"),
+    ] {
+        assert!(Country::user_assigned(code, value, alpha2, alpha3, "Test").is_err());
+    }
+}
@@ -0,0 +1,62 @@
+//! Alias table tests
+use celes::{Country, CountryTable, EMPTY_LOOKUP_TABLE, EmptyLookupTable, LookupTable};
+
+#[test]
+fn contains_ignores_case() {
+    let aliases = Country::heard_island_and_mc_donald_islands().aliases();
+    assert!(aliases.contains("McDonaldIslands"));
+    assert!(aliases.contains("mcdonaldislands"));
+    assert!(aliases.contains("HEARDISLAND"));
+    assert!(!aliases.contains("Heard Island"));
+
+    let accented = CountryTable::new(&["Türkiye"]);
+    assert!(accented.contains("TÜRKIYE"));
+    assert!(!accented.contains("turkiye"));
+}
+
+#[test]
+fn empty_and_custom_tables() {
+    assert!(Country::germany().aliases().is_empty());
+    assert_eq!(CountryTable::EMPTY, Country::germany().aliases());
+
+    let table = CountryTable::from(&["Blighty", "Old Blighty"][..]);
+    assert_eq!(2, table.len());
+    assert_eq!("[Blighty,Old Blighty]", table.to_string());
+    assert_eq!(
+        "[\"Blighty\",\"Old Blighty\"]",
+        serde_json::to_string(&table).expect("serialize")
+    );
+}
+
+#[test]
+fn serde_round_trip() {
+    let aliases = Country::the_netherlands().aliases();
+    let json = serde_json::to_string(&aliases).expect("serialize");
+    assert_eq!(
+        aliases,
+        serde_json::from_str::<CountryTable>(&json).expect("deserialize")
+    );
+    assert_eq!(
+        CountryTable::EMPTY,
+        serde_json::from_str::<CountryTable>("[]").expect("empty")
+    );
+    assert!(serde_json::from_str::<CountryTable>(r#"["Netherlands"]"#).is_err());
+    assert!(serde_json::from_str::<CountryTable>(r#"["Blighty"]"#).is_err());
+
+    let json = serde_json::to_string(&EMPTY_LOOKUP_TABLE).expect("serialize");
+    assert_eq!(
+        EMPTY_LOOKUP_TABLE,
+        serde_json::from_str::<EmptyLookupTable>(&json).expect("deserialize")
+    );
+}
+
+#[test]
+fn compare_across_table_types() {
+    assert_eq!(EMPTY_LOOKUP_TABLE, CountryTable::EMPTY);
+    assert_eq!(CountryTable::EMPTY, EMPTY_LOOKUP_TABLE);
+    let aliases = Country::the_netherlands().aliases();
+    assert_ne!(EMPTY_LOOKUP_TABLE, aliases);
+    assert!(aliases > EMPTY_LOOKUP_TABLE);
+    assert!(aliases > CountryTable::new(&["Holland"]));
+    assert!(CountryTable::new(&["A", "B"]) < CountryTable::new(&["A", "C"]));
+}
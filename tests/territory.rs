@@ -0,0 +1,46 @@
+//! User-assigned territory registration tests
+#![cfg(feature = "std")]
+use celes::Country;
+use core::str::FromStr;
+
+// Registration is process-wide so everything runs in one test to avoid interference
+#[test]
+fn registered_territories_round_trip() {
+    let waters = Country::user_assigned("901", 901, "XA", "XAA", "International Waters")
+        .expect("user-assigned");
+    let oceania =
+        Country::user_assigned("902", 902, "QO", "QOO", "Outlying Oceania").expect("user-assigned");
+    assert!(Country::from_alpha2("XA").is_err());
+
+    Country::register_user_assigned(waters).expect("XA");
+    Country::register_user_assigned(oceania).expect("QO");
+    Country::register_user_assigned(waters).expect("same territory again");
+    let clash = Country::user_assigned("903", 903, "XA", "XAB", "Clash").expect("user-assigned");
+    assert!(Country::register_user_assigned(clash).is_err());
+    assert!(Country::register_user_assigned(Country::germany()).is_err());
+    assert_eq!(2, Country::registered_user_assigned().len());
+
+    assert_eq!(waters, Country::from_alpha2("xa").expect("alpha2"));
+    assert_eq!(waters, Country::from_alpha3("XAA").expect("alpha3"));
+    assert_eq!(waters, Country::from_value(901).expect("value"));
+    assert_eq!(waters, Country::from_code("901").expect("code"));
+    assert_eq!(
+        waters,
+        Country::from_name("InternationalWaters").expect("name")
+    );
+    assert_eq!(oceania, Country::from_str("qoo").expect("from_str"));
+    assert_eq!(
+        "Outlying Oceania",
+        Country::from_str("QO").expect("from_str").long_name()
+    );
+
+    let json = serde_json::to_string(&[waters, oceania]).expect("serialize");
+    assert_eq!(r#"["XA","QO"]"#, json);
+    let parsed: Vec<Country> = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(vec![waters, oceania], parsed);
+
+    assert_eq!(Some(waters), Country::unregister_user_assigned("xa"));
+    assert!(Country::from_alpha2("XA").is_err());
+    assert_eq!(Some(oceania), Country::unregister_user_assigned("QO"));
+    assert!(Country::registered_user_assigned().is_empty());
+}
@@ -0,0 +1,117 @@
+//! Free text mention tests
+use celes::{Country, text::find_countries};
+
+fn countries(text: &str) -> Vec<Country> {
+    find_countries(text).map(|(c, _, _)| c).collect()
+}
+
+#[test]
+fn byte_ranges() {
+    let text = "shipping from Viet Nam to the UK via Singapore";
+    let mentions: Vec<_> = find_countries(text).collect();
+    assert_eq!(3, mentions.len());
+    assert_eq!((Country::vietnam(), 14..22, "Viet Nam"), mentions[0]);
+    assert_eq!(
+        (
+            Country::the_united_kingdom_of_great_britain_and_northern_ireland(),
+            30..32,
+            "UK"
+        ),
+        mentions[1]
+    );
+    assert_eq!((Country::singapore(), 37..46, "Singapore"), mentions[2]);
+    for (_, range, form) in mentions {
+        assert_eq!(&text[range], form);
+    }
+}
+
+#[test]
+fn longest_match_wins() {
+    let mentions: Vec<_> =
+        find_countries("Flights to The United Kingdom of Great Britain and Northern Ireland")
+            .collect();
+    assert_eq!(1, mentions.len());
+    assert_eq!(
+        "The United Kingdom of Great Britain and Northern Ireland",
+        mentions[0].2
+    );
+    assert_eq!(
+        vec![Country::papua_new_guinea(), Country::guinea()],
+        countries("Papua New Guinea and Guinea")
+    );
+}
+
+#[test]
+fn demonyms() {
+    assert_eq!(
+        vec![
+            Country::vietnam(),
+            Country::germany(),
+            Country::new_zealand()
+        ],
+        countries("A Vietnamese supplier, a German buyer and a New Zealander")
+    );
+}
+
+#[test]
+fn accents_and_joiners() {
+    assert_eq!(
+        vec![
+            Country::coted_ivoire(),
+            Country::turkiye(),
+            Country::saint_lucia(),
+            Country::guinea_bissau()
+        ],
+        countries("Côte d'Ivoire, Türkiye, St. Lucia and Guinea-Bissau")
+    );
+}
+
+#[test]
+fn word_boundaries() {
+    assert!(countries("Germanyx Franceville Chinatown").is_empty());
+    assert_eq!(vec![Country::france()], countries("(France)"));
+}
+
+#[test]
+fn lowercase_text() {
+    assert_eq!(
+        vec![
+            Country::vietnam(),
+            Country::the_united_kingdom_of_great_britain_and_northern_ireland()
+        ],
+        countries("shipping from viet nam to the uk")
+    );
+    assert_eq!(
+        vec![Country::coted_ivoire(), Country::the_netherlands()],
+        countries("côte d'ivoire and the netherlands")
+    );
+}
+
+#[test]
+fn capitalized_ignores_lowercase_words() {
+    let text = "a turkey sandwich with china plates";
+    assert_eq!(vec![Country::turkiye(), Country::china()], countries(text));
+    assert!(find_countries(text).capitalized(true).next().is_none());
+    assert_eq!(
+        vec![Country::the_netherlands()],
+        find_countries("the Netherlands")
+            .capitalized(true)
+            .map(|(c, _, _)| c)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn codes_are_opt_in() {
+    let text = "Ships to DE, FRA and IT";
+    assert!(countries(text).is_empty());
+    let codes: Vec<_> = find_countries(text)
+        .codes(true)
+        .map(|(c, _, _)| c)
+        .collect();
+    assert_eq!(
+        vec![Country::germany(), Country::france(), Country::italy()],
+        codes
+    );
+    assert!(find_countries("Ships to de").codes(true).next().is_none());
+}
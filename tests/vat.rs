@@ -0,0 +1,80 @@
+//! VAT number tests
+use celes::{
+    Country,
+    vat::{self, VatError},
+};
+
+#[test]
+fn prefixes_round_trip() {
+    for c in &Country::get_countries() {
+        if let Some(prefix) = c.vat_prefix() {
+            assert_eq!(Country::from_vat_prefix(prefix), Ok(*c), "{prefix}");
+        }
+    }
+    assert_eq!(
+        Country::get_countries()
+            .iter()
+            .filter(|c| c.vat_prefix().is_some())
+            .count(),
+        27
+    );
+    assert_eq!(
+        None,
+        Country::the_united_kingdom_of_great_britain_and_northern_ireland().vat_prefix()
+    );
+    assert_eq!(
+        Ok(Country::the_united_kingdom_of_great_britain_and_northern_ireland()),
+        Country::from_vat_prefix("XI")
+    );
+}
+
+#[test]
+fn validate() {
+    for input in [
+        "ATU13585627",
+        "BE0776091951",
+        "BG175074752",
+        "CY10259033P",
+        "CZ25123891",
+        "DE136695976",
+        "DK13585628",
+        "EE100931558",
+        "EL094259216",
+        "ES B-58378431",
+        "ES54362315K",
+        "FI20774740",
+        "FR40303265045",
+        "HR33392005961",
+        "HU12892312",
+        "IE6433435F",
+        "IE8D79739I",
+        "IE3628739UA",
+        "IT00743110157",
+        "LT119511515",
+        "LU15027442",
+        "LV40003521600",
+        "MT11679112",
+        "NL004495445B01",
+        "PL8567346215",
+        "PT501964843",
+        "RO18547290",
+        "SE123456789701",
+        "SI50223054",
+        "SK2022749619",
+        "XI980780684",
+        "XIGD001",
+    ] {
+        let res = vat::validate(input);
+        assert!(res.is_ok(), "validate({input}) - {res:?}");
+    }
+
+    assert_eq!(vat::validate("ATU13585628"), Err(VatError::InvalidChecksum));
+    assert_eq!(vat::validate("ES54362315T"), Err(VatError::InvalidChecksum));
+    assert_eq!(vat::validate("IE6433435G"), Err(VatError::InvalidChecksum));
+    assert_eq!(vat::validate("XIHA001"), Err(VatError::InvalidChecksum));
+    assert_eq!(
+        vat::validate("NL004495445C01"),
+        Err(VatError::InvalidFormat)
+    );
+    assert_eq!(vat::validate("CH123456789"), Err(VatError::UnknownPrefix));
+}